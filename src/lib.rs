@@ -97,25 +97,36 @@ mod err;
 
 pub mod method;
 
-#[cfg(any(feature = "http", feature = "ws"))]
-#[cfg_attr(docsrs, doc(cfg(any(feature = "http", feature = "ws"))))]
+#[cfg(any(feature = "http", feature = "ws", feature = "uds"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "http", feature = "ws", feature = "uds"))))]
 pub mod net;
 pub mod param;
-#[cfg(any(feature = "http", feature = "ws"))]
-#[cfg_attr(docsrs, doc(cfg(any(feature = "http", feature = "ws"))))]
+#[cfg(any(feature = "http", feature = "ws", feature = "uds"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "http", feature = "ws", feature = "uds"))))]
 pub mod protocol;
+#[cfg(feature = "session")]
+#[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+pub mod session;
+#[cfg(feature = "tower")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tower")))]
+pub mod service;
 
 pub use err::Error;
 pub use err::ErrorKind;
 use method::query_response::QueryResponse;
 
+use crate::param::Authenticator;
+use crate::param::Jwt;
 use crate::param::ServerAddrs;
 use crate::param::ToServerAddrs;
 use flume::Receiver;
 use flume::Sender;
+use futures::stream::Stream;
+use method::live::Notification;
 use method::Method;
 use once_cell::sync::OnceCell;
 use semver::BuildMetadata;
+use semver::Version;
 use semver::VersionReq;
 use serde::de::DeserializeOwned;
 use std::fmt::Debug;
@@ -124,10 +135,16 @@ use std::future::IntoFuture;
 use std::marker::PhantomData;
 use std::pin::Pin;
 #[cfg(feature = "ws")]
-use std::sync::atomic::AtomicI64;
+use std::collections::HashMap;
 #[cfg(feature = "ws")]
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+#[cfg(feature = "ws")]
+use surrealdb::sql::Uuid;
 use surrealdb::sql::Value;
 
 /// Result type returned by the client
@@ -138,6 +155,9 @@ pub type Response = QueryResponse;
 
 const SUPPORTED_VERSIONS: (&str, &str) = (">=1.0.0-beta.8, <2.0.0", "20221030.c12a1cc");
 
+/// Minimum server version [`Capabilities::live_queries`] requires
+const LIVE_QUERY_MIN_VERSION: &str = ">=1.0.0-beta.9, <2.0.0";
+
 /// Connection trait implemented by supported protocols
 pub trait Connection: Sized + Send + Sync + 'static {
     /// The payload the caller sends to the router
@@ -176,6 +196,14 @@ pub trait Connection: Sized + Send + Sync + 'static {
         receiver: Receiver<Self::Response>,
     ) -> Pin<Box<dyn Future<Output = Result<Response>> + Send + Sync + '_>>;
 
+    /// Receive the notifications of a live query as a stream
+    fn recv_notifications<R>(
+        &mut self,
+        receiver: Receiver<Self::Response>,
+    ) -> Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>
+    where
+        R: DeserializeOwned + Send + Sync + 'static;
+
     /// Execute all methods except `query`
     fn execute<'r, R>(
         &'r mut self,
@@ -202,6 +230,21 @@ pub trait Connection: Sized + Send + Sync + 'static {
             self.recv_query(rx).await
         })
     }
+
+    /// Execute the `live` method, returning a stream of notifications
+    fn execute_notifications<'r, R>(
+        &'r mut self,
+        router: &'r Router<Self>,
+        param: param::Param,
+    ) -> Pin<Box<dyn Future<Output = Result<Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>>> + Send + Sync + 'r>>
+    where
+        R: DeserializeOwned + Send + Sync + 'static,
+    {
+        Box::pin(async move {
+            let rx = self.send(router, param).await?;
+            Ok(self.recv_notifications(rx))
+        })
+    }
 }
 
 /// Connect future created by `Surreal::connect`
@@ -210,11 +253,19 @@ pub struct Connect<'r, C: Connection, Response> {
     router: Option<&'r OnceCell<Arc<Router<C>>>>,
     address: Result<ServerAddrs>,
     capacity: usize,
+    /// Connections to open eagerly if this builder is turned into a [`Pool`] via [`Connect::pool`]
+    min_idle: usize,
+    /// Connections [`Pool`] may hold at once, see [`Connect::pool`]
+    max_size: usize,
+    /// How long [`Pool::client`] waits for a connection before giving up, see [`Connect::acquire_timeout`]
+    acquire_timeout: Option<Duration>,
+    /// Whether to refuse an unsupported server version during `connect`, see [`Connect::skip_version_check`]
+    version_check: bool,
     client: PhantomData<C>,
     response_type: PhantomData<Response>,
 }
 
-impl<C, R> Connect<'_, C, R>
+impl<'r, C, R> Connect<'r, C, R>
 where
     C: Connection,
 {
@@ -249,6 +300,128 @@ where
         self.capacity = capacity;
         self
     }
+
+    /// Maintains `size` connections instead of the default single connection
+    ///
+    /// Shorthand for [`pool(size, size)`](Self::pool): opens exactly `size`
+    /// connections up front and never grows the pool beyond that.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> surrealdb_rs::Result<()> {
+    /// use surrealdb_rs::protocol::Ws;
+    /// use surrealdb_rs::Surreal;
+    ///
+    /// let pool = Surreal::connect::<Ws>("localhost:8000")
+    ///     .pool_size(8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pool_size(self, size: usize) -> Connect<'r, C, Pool<C>> {
+        self.pool(size, size)
+    }
+
+    /// Maintains between `min_idle` and `max_size` connections instead of the
+    /// default single connection
+    ///
+    /// Turns this builder into one that resolves to a [`Pool`] rather than a
+    /// plain [`Surreal`] client. `min_idle` connections are opened up front;
+    /// [`Pool::client`] prunes a connection that has disconnected or failed a
+    /// `health()` check instead of handing it out and opens a replacement,
+    /// never holding more than `max_size` connections. A new connection is
+    /// opened only to replace a pruned one or when the pool has none left;
+    /// busy connections do not make the pool grow. Call [`acquire_timeout`](Self::acquire_timeout)
+    /// to bound how long a caller waits for a connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> surrealdb_rs::Result<()> {
+    /// use surrealdb_rs::protocol::Ws;
+    /// use surrealdb_rs::Surreal;
+    ///
+    /// let pool = Surreal::connect::<Ws>("localhost:8000")
+    ///     .pool(2, 8)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pool(self, min_idle: usize, max_size: usize) -> Connect<'r, C, Pool<C>> {
+        Connect {
+            router: self.router,
+            address: self.address,
+            capacity: self.capacity,
+            min_idle,
+            max_size: max_size.max(min_idle).max(1),
+            acquire_timeout: self.acquire_timeout,
+            version_check: self.version_check,
+            client: self.client,
+            response_type: PhantomData,
+        }
+    }
+
+    /// Bounds how long [`Pool::client`] waits for a healthy connection
+    ///
+    /// By default a pool waits indefinitely. With a timeout, `Pool::client`
+    /// returns [`ErrorKind::Pool`] if it has not got a healthy connection,
+    /// including opening and health-checking a replacement, once `timeout`
+    /// elapses.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> surrealdb_rs::Result<()> {
+    /// use std::time::Duration;
+    /// use surrealdb_rs::protocol::Ws;
+    /// use surrealdb_rs::Surreal;
+    ///
+    /// let pool = Surreal::connect::<Ws>("localhost:8000")
+    ///     .pool(2, 8)
+    ///     .acquire_timeout(Duration::from_secs(5))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn acquire_timeout(mut self, timeout: Duration) -> Self {
+        self.acquire_timeout = Some(timeout);
+        self
+    }
+
+    /// Skips the server version check performed during `connect`
+    ///
+    /// By default, connecting to an unsupported server version fails with
+    /// an [`Error`] before the future resolves. Call this to connect
+    /// anyway; an unsupported server is still logged with
+    /// `tracing::warn!`, and [`Surreal::capabilities`] reflects whatever the
+    /// server actually reported.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> surrealdb_rs::Result<()> {
+    /// use surrealdb_rs::protocol::Ws;
+    /// use surrealdb_rs::Surreal;
+    ///
+    /// let client = Surreal::connect::<Ws>("localhost:8000")
+    ///     .skip_version_check()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn skip_version_check(mut self) -> Self {
+        self.version_check = false;
+        self
+    }
 }
 
 impl<'r, Client> IntoFuture for Connect<'r, Client, Surreal<Client>>
@@ -261,7 +434,7 @@ where
     fn into_future(self) -> Self::IntoFuture {
         Box::pin(async move {
             let client = Client::connect(self.address?, self.capacity).await?;
-            client.check_server_version();
+            client.negotiate_capabilities(self.version_check).await?;
             Ok(client)
         })
     }
@@ -278,13 +451,11 @@ where
         Box::pin(async move {
             match self.router {
                 Some(router) => {
-                    let option = Client::connect(self.address?, self.capacity)
-                        .await?
-                        .router
-                        .into_inner();
-                    match option {
-                        Some(client) => {
-                            let _res = router.set(client);
+                    let client = Client::connect(self.address?, self.capacity).await?;
+                    client.negotiate_capabilities(self.version_check).await?;
+                    match client.router.into_inner() {
+                        Some(inner) => {
+                            let _res = router.set(inner);
                         }
                         None => unreachable!(),
                     }
@@ -296,6 +467,40 @@ where
     }
 }
 
+impl<'r, Client> IntoFuture for Connect<'r, Client, Pool<Client>>
+where
+    Client: Connection,
+{
+    type Output = Result<Pool<Client>>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let address = self.address?;
+            let min_idle = self.min_idle.min(self.max_size);
+            let mut routers = Vec::with_capacity(min_idle);
+            for _ in 0..min_idle {
+                let client = Client::connect(address.clone(), self.capacity).await?;
+                client.negotiate_capabilities(self.version_check).await?;
+                let router = client.router.into_inner().ok_or_else(connection_uninitialised)?;
+                routers.push(router);
+            }
+            Ok(Pool {
+                address,
+                capacity: self.capacity,
+                max_size: self.max_size,
+                acquire_timeout: self.acquire_timeout,
+                version_check: self.version_check,
+                routers: Mutex::new(routers),
+                next: AtomicUsize::new(0),
+                authenticator: OnceCell::new(),
+                last_token: Mutex::new(None),
+                reserved: AtomicUsize::new(0),
+            })
+        })
+    }
+}
+
 #[derive(Debug)]
 struct Route<A, R> {
     request: A,
@@ -309,6 +514,26 @@ pub struct Router<C: Connection> {
     sender: Sender<Option<Route<C::Request, C::Response>>>,
     #[cfg(feature = "ws")]
     last_id: AtomicI64,
+    /// Live query id -> subscriber channel, alongside the request id routing above
+    ///
+    /// The WebSocket read loop tags every inbound live-query notification
+    /// frame with the subscription id `LIVE SELECT` returned; this map lets
+    /// it forward the frame straight to the matching [`Notifications`](method::live::Notifications)
+    /// stream instead of round-tripping it through the regular request/response
+    /// routing. Reconnecting re-populates it by re-registering every live
+    /// query that was still outstanding.
+    #[cfg(feature = "ws")]
+    live_senders: Mutex<HashMap<Uuid, Sender<C::Response>>>,
+    /// The capabilities negotiated with the server during `connect`
+    capabilities: OnceCell<Capabilities>,
+    /// Serializes [`Surreal::transaction`] calls sharing this router
+    ///
+    /// A `BEGIN`/`COMMIT` transaction is session state on the underlying
+    /// connection, not something the protocol scopes per request; two
+    /// overlapping `.transaction()` calls against clients that share this
+    /// router would otherwise interleave their statements inside the same
+    /// `BEGIN`/`COMMIT` window. Held for the duration of one transaction.
+    transaction_lock: tokio::sync::Mutex<()>,
 }
 
 impl<C> Router<C>
@@ -319,6 +544,66 @@ where
     fn next_id(&self) -> i64 {
         self.last_id.fetch_add(1, Ordering::SeqCst)
     }
+
+    /// Whether a request can currently be queued without blocking
+    ///
+    /// Mirrors the bounded `flume` capacity configured via
+    /// [`Connect::with_capacity`]; an unbounded router (the default) is
+    /// always ready.
+    pub(crate) fn is_ready(&self) -> bool {
+        match self.sender.capacity() {
+            Some(capacity) => self.sender.len() < capacity,
+            None => true,
+        }
+    }
+
+    /// Registers the subscriber channel for a live query id
+    #[cfg(feature = "ws")]
+    pub(crate) fn register_live(&self, id: Uuid, sender: Sender<C::Response>) {
+        self.live_senders.lock().unwrap().insert(id, sender);
+    }
+
+    /// Removes a live query's subscriber channel, e.g. once its stream is
+    /// dropped and the matching `KILL` has been sent
+    #[cfg(feature = "ws")]
+    pub(crate) fn unregister_live(&self, id: &Uuid) {
+        self.live_senders.lock().unwrap().remove(id);
+    }
+
+    /// Forwards a frame tagged with `id` to its subscriber
+    ///
+    /// Returns `false` if nothing is registered for `id` anymore, so the
+    /// read loop can discard the frame.
+    #[cfg(feature = "ws")]
+    pub(crate) fn forward_live(&self, id: &Uuid, response: C::Response) -> bool {
+        match self.live_senders.lock().unwrap().get(id) {
+            Some(sender) => {
+                let _ = sender.send(response);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the connection backing this router has gone away
+    ///
+    /// The connection task holds the other end of `sender`; once it exits
+    /// (the socket dropped, the process died, ...) the channel becomes
+    /// disconnected, which is exactly the signal [`Pool`] uses to prune a
+    /// dead router instead of routing more requests to it.
+    pub(crate) fn is_disconnected(&self) -> bool {
+        self.sender.is_disconnected()
+    }
+
+    /// Returns the capabilities negotiated with the server during `connect`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorKind::ConnectionUninitialized`] if the connection
+    /// hasn't finished connecting yet.
+    pub(crate) fn capabilities(&self) -> Result<&Capabilities> {
+        self.capabilities.get().ok_or_else(connection_uninitialised)
+    }
 }
 
 impl<C> Drop for Router<C>
@@ -330,38 +615,123 @@ where
     }
 }
 
+/// The server version and feature set negotiated during `connect`
+///
+/// Returned by [`Surreal::capabilities`]. Method builders can consult this
+/// to fail fast with a clear error when a feature isn't supported by the
+/// connected server, instead of sending it a request it has no chance of
+/// understanding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    server_version: Version,
+    live_queries: bool,
+}
+
+impl Capabilities {
+    /// The version reported by the connected server
+    pub fn server_version(&self) -> &Version {
+        &self.server_version
+    }
+
+    /// Whether the connected server supports live queries
+    pub fn live_queries(&self) -> bool {
+        self.live_queries
+    }
+}
+
 /// `SurrealDB` client
 #[derive(Debug)]
 pub struct Surreal<C: Connection> {
     router: OnceCell<Arc<Router<C>>>,
+    authenticator: OnceCell<Arc<dyn Authenticator>>,
 }
 
 impl<C> Surreal<C>
 where
     C: Connection,
 {
-    fn check_server_version(&self) {
-        let conn = self.clone();
-        tokio::spawn(async move {
-            let (versions, build_meta) = SUPPORTED_VERSIONS;
-            // invalid version requirements should be caught during development
-            let req = VersionReq::parse(versions).expect("valid supported versions");
-            let build_meta =
-                BuildMetadata::new(build_meta).expect("valid supported build metadata");
-            match conn.version().await {
-                Ok(version) => {
-                    let server_build = &version.build;
-                    if !req.matches(&version) {
-                        tracing::warn!("server version `{version}` does not match the range supported by the client `{versions}`");
-                    } else if server_build < &build_meta {
-                        tracing::warn!("server build `{server_build}` is older than the minimum supported build `{build_meta}`");
-                    }
-                }
-                Err(error) => {
-                    tracing::trace!("failed to lookup the server version; {error:?}");
-                }
-            }
+    /// Installs an [`Authenticator`] that re-signs this connection in after a reconnect
+    ///
+    /// The only reconnect path this crate has today is [`Pool`]'s, which
+    /// opens a fresh connection to replace one that died; a plain, unpooled
+    /// `Surreal` never reconnects on its own, so installing an authenticator
+    /// here has no effect yet. For a pooled connection, install the
+    /// authenticator on the [`Pool`] itself with [`Pool::use_authenticator`]
+    /// before acquiring clients from it, so every connection it (re)opens is
+    /// signed back in with [`Authenticator::evaluate_challenge`] instead of
+    /// the caller having to notice the drop and call `signin` again by hand.
+    ///
+    /// Returns an error if an authenticator is already installed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use surrealdb_rs::{Result, Surreal};
+    /// # use surrealdb_rs::net::WsClient;
+    /// # use surrealdb_rs::param::Authenticator;
+    /// # fn authenticator() -> impl Authenticator + 'static { todo!() }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// # let db = Surreal::<WsClient>::new();
+    /// db.use_authenticator(authenticator())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn use_authenticator(&self, authenticator: impl Authenticator + 'static) -> Result<()> {
+        self.authenticator.set(Arc::new(authenticator)).map_err(|_| {
+            ErrorKind::Authentication.with_message("an authenticator is already installed")
+        })
+    }
+
+    pub(crate) fn authenticator(&self) -> Option<Arc<dyn Authenticator>> {
+        self.authenticator.get().cloned()
+    }
+
+    /// Returns the capabilities negotiated with the server during `connect`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorKind::ConnectionUninitialized`] if the connection
+    /// hasn't finished connecting yet.
+    pub fn capabilities(&self) -> Result<&Capabilities> {
+        self.router.extract()?.capabilities()
+    }
+
+    /// Returns the version reported by the connected server
+    ///
+    /// Shorthand for `self.capabilities()?.server_version()`.
+    pub fn server_version(&self) -> Result<&Version> {
+        Ok(self.capabilities()?.server_version())
+    }
+
+    /// Fetches the server version, turns it into a [`Capabilities`] set, and stores it
+    ///
+    /// When `version_check` is `true` (the default), a server outside
+    /// [`SUPPORTED_VERSIONS`] is refused with a hard [`Error`] instead of
+    /// only being logged, so a client never sends requests a server is known
+    /// not to understand.
+    async fn negotiate_capabilities(&self, version_check: bool) -> Result<()> {
+        let (versions, build_meta) = SUPPORTED_VERSIONS;
+        // invalid version requirements should be caught during development
+        let req = VersionReq::parse(versions).expect("valid supported versions");
+        let build_meta = BuildMetadata::new(build_meta).expect("valid supported build metadata");
+        let version = self.version().await?;
+        let supported = req.matches(&version) && version.build >= build_meta;
+        if version_check && !supported {
+            return Err(ErrorKind::Version.with_message(format!(
+                "server version `{version}` is not supported by this client, which requires `{versions}` with build `{build_meta}` or newer"
+            )));
+        } else if !supported {
+            tracing::warn!("server version `{version}` is not supported by this client, which requires `{versions}` with build `{build_meta}` or newer; continuing because the version check was skipped");
+        }
+        let live_queries = VersionReq::parse(LIVE_QUERY_MIN_VERSION)
+            .expect("valid live query version requirement")
+            .matches(&version);
+        let _res = self.router.extract()?.capabilities.set(Capabilities {
+            server_version: version,
+            live_queries,
         });
+        Ok(())
     }
 }
 
@@ -372,8 +742,188 @@ where
     fn clone(&self) -> Self {
         Self {
             router: self.router.clone(),
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+/// A pool of connections to the same server, opened with `Connect::pool`
+///
+/// Every call is dispatched through [`Pool::client`], which round-robins over
+/// the pool's healthy connections, replacing one that has disconnected or
+/// failed its health check and reconnecting once it has none left, up to
+/// `max_size` connections. The
+/// returned [`Surreal`] is a cheap handle onto one of the pool's routers, so
+/// the full `create`/`select`/`query`/... method surface is available on it
+/// unchanged; there is no separate, pool-specific method API to keep in sync.
+#[derive(Debug)]
+pub struct Pool<C: Connection> {
+    address: ServerAddrs,
+    capacity: usize,
+    max_size: usize,
+    acquire_timeout: Option<Duration>,
+    version_check: bool,
+    routers: Mutex<Vec<Arc<Router<C>>>>,
+    next: AtomicUsize,
+    authenticator: OnceCell<Arc<dyn Authenticator>>,
+    last_token: Mutex<Option<Jwt>>,
+    /// Connections a concurrent `reconnect` has claimed but not pushed to
+    /// `routers` yet, counted against `max_size` so two callers can't both
+    /// pass the capacity check and overshoot it
+    reserved: AtomicUsize,
+}
+
+impl<C> Pool<C>
+where
+    C: Connection,
+{
+    /// Installs an [`Authenticator`] that re-signs every connection this pool
+    /// (re)opens back in
+    ///
+    /// Mirrors [`Surreal::use_authenticator`], but applies pool-wide: every
+    /// connection [`reconnect`](Self::reconnect) opens to replace a dead one
+    /// is re-authenticated with it before being handed out, using
+    /// [`Authenticator::initial_response`] the first time and
+    /// [`Authenticator::evaluate_challenge`] on every connection after that.
+    ///
+    /// Returns an error if an authenticator is already installed.
+    pub fn use_authenticator(&self, authenticator: impl Authenticator + 'static) -> Result<()> {
+        self.authenticator.set(Arc::new(authenticator)).map_err(|_| {
+            ErrorKind::Authentication.with_message("an authenticator is already installed")
+        })
+    }
+
+    /// Returns a client bound to one of this pool's healthy connections
+    ///
+    /// Disconnected routers are pruned first, then the next router in
+    /// round-robin order is validated with [`Surreal::health`] before being
+    /// handed back; a router that fails either check is dropped and a
+    /// replacement is opened, up to `max_size` connections. An error opening
+    /// the replacement is returned as is. If no replacement can be opened
+    /// because other callers' connection attempts already fill `max_size`,
+    /// this backs off and retries. [`Connect::acquire_timeout`] bounds the
+    /// whole wait, returning [`ErrorKind::Pool`] once it elapses; without it,
+    /// there is no bound.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use surrealdb_rs::{Result, Surreal};
+    /// # use surrealdb_rs::protocol::Ws;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let pool = Surreal::connect::<Ws>("localhost:8000").pool(2, 8).await?;
+    /// let people: Vec<serde_json::Value> = pool.client().await?.select("person").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn client(&self) -> Result<Surreal<C>> {
+        let router = match self.acquire_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.router())
+                .await
+                .map_err(|_| ErrorKind::Pool.with_message("timed out acquiring a connection"))??,
+            None => self.router().await?,
+        };
+        let client = Surreal {
+            router: OnceCell::new(),
+            authenticator: OnceCell::new(),
+        };
+        let _res = client.router.set(router);
+        Ok(client)
+    }
+
+    async fn router(&self) -> Result<Arc<Router<C>>> {
+        const MIN_BACKOFF: Duration = Duration::from_millis(10);
+        const MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            let selected = {
+                let mut routers = self.routers.lock().unwrap();
+                routers.retain(|router| !router.is_disconnected());
+                match routers.len() {
+                    0 => None,
+                    len => {
+                        let index = self.next.fetch_add(1, Ordering::Relaxed) % len;
+                        Some(routers[index].clone())
+                    }
+                }
+            };
+            let grew = match selected {
+                Some(router) => {
+                    if self.is_healthy(&router).await {
+                        return Ok(router);
+                    }
+                    self.routers.lock().unwrap().retain(|candidate| !Arc::ptr_eq(candidate, &router));
+                    self.reconnect().await?
+                }
+                None => self.reconnect().await?,
+            };
+            // `reconnect` is a no-op once the pool is at `max_size`; if every
+            // connection we can see is unhealthy and nothing grew, back off
+            // instead of hammering `health()` in a tight loop.
+            if grew {
+                backoff = MIN_BACKOFF;
+            } else {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
         }
     }
+
+    /// Runs a `health()` call over `router`, the same check [`Connect`] runs
+    /// right after opening a fresh connection
+    async fn is_healthy(&self, router: &Arc<Router<C>>) -> bool {
+        let client = Surreal {
+            router: OnceCell::new(),
+            authenticator: OnceCell::new(),
+        };
+        let _res = client.router.set(router.clone());
+        client.health().await.is_ok()
+    }
+
+    /// Opens and adds one more connection to the pool, up to `max_size`
+    ///
+    /// Returns whether a connection was actually added. The check against
+    /// `max_size` and the reservation of the slot it's checking happen under
+    /// the same lock, so two concurrent callers can't both pass the check
+    /// and push, overshooting `max_size`; the reservation is released once
+    /// the connection attempt finishes, whether it succeeded or not.
+    async fn reconnect(&self) -> Result<bool> {
+        {
+            let routers = self.routers.lock().unwrap();
+            if routers.len() + self.reserved.load(Ordering::SeqCst) >= self.max_size {
+                return Ok(false);
+            }
+            self.reserved.fetch_add(1, Ordering::SeqCst);
+        }
+        let _reservation = ReleaseReservation(&self.reserved);
+
+        let client = C::connect(self.address.clone(), self.capacity).await?;
+        client.negotiate_capabilities(self.version_check).await?;
+        if let Some(authenticator) = self.authenticator.get() {
+            let previous = self.last_token.lock().unwrap().clone();
+            let token = match &previous {
+                Some(previous) => authenticator.evaluate_challenge(previous).await?,
+                None => authenticator.initial_response().await?,
+            };
+            client.authenticate(token.clone()).await?;
+            *self.last_token.lock().unwrap() = Some(token);
+        }
+        let router = client.router.into_inner().ok_or_else(connection_uninitialised)?;
+        self.routers.lock().unwrap().push(router);
+        Ok(true)
+    }
+}
+
+/// Releases a slot reserved by [`Pool::reconnect`] once the connection
+/// attempt it guarded finishes, successfully or not
+struct ReleaseReservation<'a>(&'a AtomicUsize);
+
+impl Drop for ReleaseReservation<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 /// Exposes a `connect` method for use with `Surreal::new`
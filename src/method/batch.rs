@@ -0,0 +1,163 @@
+use crate::method::query::response::QueryResult;
+use crate::method::query::Query;
+use crate::Connection;
+use crate::ErrorKind;
+use crate::Result;
+use crate::Surreal;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use surrealdb::sql::statements::BeginStatement;
+use surrealdb::sql::statements::CommitStatement;
+use surrealdb::sql::Value;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Starts a batch of create/merge/patch/delete operations submitted
+	/// together as a single `BEGIN`/`COMMIT` transaction
+	///
+	/// Every mutating method on [`Surreal`] targets one record, one table, or
+	/// one range, which costs a round trip each -- fine for a handful of
+	/// writes, wasteful for bulk ingestion. A batch instead accumulates
+	/// operations and, once awaited, assembles them all into a single
+	/// multi-statement query bracketed by [`BeginStatement`]/[`CommitStatement`]
+	/// (exactly what chaining them through [`query`](Self::query) by hand
+	/// does), and submits it in one round trip. The result is a [`QueryResult`]
+	/// per operation, in submission order, so a failed operation doesn't
+	/// prevent reading the others' results.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[derive(serde::Deserialize, Default)]
+	/// # struct User { name: String }
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let results = db
+	///     .batch()
+	///     .create("user", serde_json::json!({ "name": "John" }))
+	///     .merge("user:john", serde_json::json!({ "age": 30 }))
+	///     .delete("user:jane")
+	///     .await?;
+	///
+	/// let created: User = results[0].get(0)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn batch(&self) -> Batch<C> {
+		Batch {
+			query: self.query(BeginStatement),
+			operations: 0,
+		}
+	}
+}
+
+/// A batch of operations accumulated by [`Surreal::batch`]
+#[derive(Debug)]
+pub struct Batch<C: Connection> {
+	query: Query<C>,
+	operations: usize,
+}
+
+impl<C> Batch<C>
+where
+	C: Connection,
+{
+	/// Appends a `CREATE resource CONTENT ...` operation to the batch
+	#[must_use]
+	pub fn create(self, resource: impl Into<Value>, content: impl Serialize) -> Self {
+		self.push("CREATE", "CONTENT", resource, content)
+	}
+
+	/// Appends an `UPDATE resource CONTENT ...` operation to the batch,
+	/// replacing the targeted record(s) entirely
+	#[must_use]
+	pub fn update(self, resource: impl Into<Value>, content: impl Serialize) -> Self {
+		self.push("UPDATE", "CONTENT", resource, content)
+	}
+
+	/// Appends an `UPDATE resource MERGE ...` operation to the batch,
+	/// merging `content` into the targeted record(s)
+	#[must_use]
+	pub fn merge(self, resource: impl Into<Value>, content: impl Serialize) -> Self {
+		self.push("UPDATE", "MERGE", resource, content)
+	}
+
+	/// Appends an `UPDATE resource PATCH ...` operation to the batch,
+	/// applying a JSON Patch to the targeted record(s)
+	#[must_use]
+	pub fn patch(self, resource: impl Into<Value>, patches: impl Serialize) -> Self {
+		self.push("UPDATE", "PATCH", resource, patches)
+	}
+
+	/// Appends a `DELETE resource` operation to the batch
+	#[must_use]
+	pub fn delete(mut self, resource: impl Into<Value>) -> Self {
+		let binding = format!("batch_resource_{}", self.operations);
+		self.query = self
+			.query
+			.query(format!("DELETE ${binding}"))
+			.bind((binding, resource.into()));
+		self.operations += 1;
+		self
+	}
+
+	/// Appends a `<keyword> $resource <clause> $content` statement to the
+	/// batch, binding both the resource and the content as parameters --
+	/// the same way every other method on [`Surreal`] sends a resource,
+	/// rather than interpolating either into the query text
+	fn push(
+		mut self,
+		keyword: &str,
+		clause: &str,
+		resource: impl Into<Value>,
+		content: impl Serialize,
+	) -> Self {
+		let n = self.operations;
+		let resource_binding = format!("batch_resource_{n}");
+		let content_binding = format!("batch_content_{n}");
+		self.query = self
+			.query
+			.query(format!("{keyword} ${resource_binding} {clause} ${content_binding}"))
+			.bind((resource_binding, resource.into()))
+			.bind((content_binding, content));
+		self.operations += 1;
+		self
+	}
+}
+
+impl<Client> IntoFuture for Batch<Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<QueryResult>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let operations = self.operations;
+			let response = self.query.query(CommitStatement).await?;
+			let mut results = response.into_inner();
+
+			// the first result is `BEGIN`'s and the last is `COMMIT`'s; neither
+			// corresponds to a submitted operation
+			if results.len() != operations + 2 {
+				return Err(ErrorKind::Query.with_context(format!(
+					"expected {} result(s) for a {operations}-operation batch, found {}",
+					operations + 2,
+					results.len()
+				)));
+			}
+			results.pop();
+			results.remove(0);
+
+			Ok(results)
+		})
+	}
+}
@@ -0,0 +1,228 @@
+//! The chunked, authenticated on-disk format shared by [`Export::with_passphrase`](super::export::Export::with_passphrase)
+//! and [`Import::with_passphrase`](super::import::Import::with_passphrase)
+//!
+//! A file starts with a header (magic, format version, the random Argon2id
+//! salt, the random base nonce, and the chunk size), followed by the dump
+//! split into `chunk_size`-sized plaintext chunks, each written as a
+//! `u32` ciphertext length followed by its XChaCha20-Poly1305 ciphertext
+//! (which already carries its own Poly1305 tag), and terminated by an
+//! [`END_MARKER`] length in place of one more chunk. Chunk `i`'s nonce is the
+//! base nonce XORed with `i` in its last 8 bytes, so no nonce is ever reused
+//! under the same key without storing one nonce per chunk.
+
+use crate::ErrorKind;
+use crate::Result;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::KeyInit;
+use chacha20poly1305::XChaCha20Poly1305;
+use chacha20poly1305::XNonce;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use std::path::Path;
+use std::path::PathBuf;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+const MAGIC: &[u8; 4] = b"SDBX";
+const VERSION: u8 = 2;
+const DEFAULT_CHUNK_SIZE: u32 = 64 * 1024;
+const SALT_LEN: usize = 16;
+const BASE_NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+/// Written in place of a chunk's length once every plaintext chunk has been
+/// written, so [`decrypt`] can tell a file that ends cleanly from one that's
+/// merely been truncated after some chunk -- a real chunk length never
+/// reaches this value, since [`decrypt`] already rejects any length bigger
+/// than the header's chunk size plus [`TAG_LEN`]
+const END_MARKER: u32 = u32::MAX;
+
+/// Derives a 32-byte key from `passphrase` and `salt` with Argon2id, using
+/// this format's fixed parameters
+fn derive_key(passphrase: &str, salt: &[u8; SALT_LEN]) -> Result<[u8; KEY_LEN]> {
+	let mut key = [0u8; KEY_LEN];
+	argon2::Argon2::default()
+		.hash_password_into(passphrase.as_bytes(), salt, &mut key)
+		.map_err(|error| ErrorKind::Crypt.with_message(error.to_string()))?;
+	Ok(key)
+}
+
+/// Builds chunk `counter`'s nonce by XORing it into `base_nonce`'s last 8 bytes
+fn chunk_nonce(base_nonce: &[u8; BASE_NONCE_LEN], counter: u64) -> XNonce {
+	let mut nonce = *base_nonce;
+	for (byte, counter_byte) in nonce[BASE_NONCE_LEN - 8..].iter_mut().zip(counter.to_be_bytes()) {
+		*byte ^= counter_byte;
+	}
+	XNonce::clone_from_slice(&nonce)
+}
+
+/// Encrypts everything read from `reader` into `writer`, under a key derived
+/// from `passphrase`
+pub(super) async fn encrypt(
+	passphrase: &str,
+	mut reader: impl AsyncRead + Unpin,
+	mut writer: impl AsyncWrite + Unpin,
+) -> Result<()> {
+	let mut salt = [0u8; SALT_LEN];
+	OsRng.fill_bytes(&mut salt);
+	let mut base_nonce = [0u8; BASE_NONCE_LEN];
+	OsRng.fill_bytes(&mut base_nonce);
+
+	writer.write_all(MAGIC).await?;
+	writer.write_all(&[VERSION]).await?;
+	writer.write_all(&salt).await?;
+	writer.write_all(&base_nonce).await?;
+	writer.write_all(&DEFAULT_CHUNK_SIZE.to_be_bytes()).await?;
+
+	let key = derive_key(passphrase, &salt)?;
+	let cipher = XChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&key));
+
+	let mut buf = vec![0u8; DEFAULT_CHUNK_SIZE as usize];
+	let mut counter = 0u64;
+	loop {
+		let read = read_fill(&mut reader, &mut buf).await?;
+		if read == 0 {
+			break;
+		}
+		let nonce = chunk_nonce(&base_nonce, counter);
+		let ciphertext = cipher
+			.encrypt(&nonce, &buf[..read])
+			.map_err(|_| ErrorKind::Crypt.with_message("failed to encrypt export chunk"))?;
+		writer.write_all(&(ciphertext.len() as u32).to_be_bytes()).await?;
+		writer.write_all(&ciphertext).await?;
+		counter += 1;
+	}
+	writer.write_all(&END_MARKER.to_be_bytes()).await?;
+	writer.flush().await?;
+	Ok(())
+}
+
+/// Reverses [`encrypt`], failing with [`ErrorKind::Crypt`] on a wrong
+/// passphrase or a tampered file rather than producing garbage plaintext
+pub(super) async fn decrypt(
+	passphrase: &str,
+	mut reader: impl AsyncRead + Unpin,
+	mut writer: impl AsyncWrite + Unpin,
+) -> Result<()> {
+	let mut magic = [0u8; 4];
+	reader.read_exact(&mut magic).await?;
+	if &magic != MAGIC {
+		return Err(ErrorKind::Crypt.with_message("not a file produced by `with_passphrase`"));
+	}
+	let mut version = [0u8; 1];
+	reader.read_exact(&mut version).await?;
+	if version[0] != VERSION {
+		return Err(ErrorKind::Crypt
+			.with_message(format!("unsupported encrypted export version {}", version[0])));
+	}
+	let mut salt = [0u8; SALT_LEN];
+	reader.read_exact(&mut salt).await?;
+	let mut base_nonce = [0u8; BASE_NONCE_LEN];
+	reader.read_exact(&mut base_nonce).await?;
+	let mut chunk_size = [0u8; 4];
+	reader.read_exact(&mut chunk_size).await?;
+	let chunk_size = u32::from_be_bytes(chunk_size) as usize;
+
+	let key = derive_key(passphrase, &salt)?;
+	let cipher = XChaCha20Poly1305::new(chacha20poly1305::Key::from_slice(&key));
+
+	let mut counter = 0u64;
+	loop {
+		let mut len = [0u8; 4];
+		match reader.read_exact(&mut len).await {
+			Ok(()) => {}
+			Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+				return Err(ErrorKind::Crypt
+					.with_message("corrupt file: truncated before its end marker"));
+			}
+			Err(error) => return Err(error.into()),
+		}
+		let len = u32::from_be_bytes(len);
+		if len == END_MARKER {
+			break;
+		}
+		let len = len as usize;
+		if len > chunk_size + TAG_LEN {
+			return Err(ErrorKind::Crypt.with_message("corrupt file: chunk larger than its header's chunk size"));
+		}
+		let mut ciphertext = vec![0u8; len];
+		reader.read_exact(&mut ciphertext).await?;
+		let plaintext = cipher.decrypt(&chunk_nonce(&base_nonce, counter), ciphertext.as_slice()).map_err(
+			|_| ErrorKind::Crypt.with_message("failed to decrypt: wrong passphrase or corrupted file"),
+		)?;
+		writer.write_all(&plaintext).await?;
+		counter += 1;
+	}
+	writer.flush().await?;
+	Ok(())
+}
+
+/// A plaintext file staged beside the real source/destination for the
+/// duration of one `with_passphrase` encrypt or decrypt pass
+///
+/// [`Export::with_passphrase`](super::export::Export::with_passphrase) and
+/// [`Import::with_passphrase`](super::import::Import::with_passphrase) both
+/// still round-trip through a real file rather than piping straight into the
+/// cipher: the embedded router only takes a [`PathBuf`](crate::param::Param::file)
+/// across the connection boundary, not an arbitrary writer/reader, so there's
+/// nowhere to hand it a pipe. This keeps the exposure as small as it can be
+/// given that constraint -- an unpredictable name instead of a sibling
+/// `.plain.tmp`, created up front with owner-only permissions so the
+/// plaintext is never briefly world- or group-readable, and removed as soon
+/// as it's dropped regardless of which step failed.
+pub(super) struct TempPlaintext(PathBuf);
+
+impl TempPlaintext {
+	/// Picks an unpredictable path in the same directory as `file`, so it
+	/// shares a filesystem with it
+	pub(super) fn beside(file: &Path) -> Self {
+		let name = format!(".surrealdb-{}.tmp", Uuid::new_v4());
+		let path = match file.parent() {
+			Some(parent) if !parent.as_os_str().is_empty() => parent.join(name),
+			_ => PathBuf::from(name),
+		};
+		Self(path)
+	}
+
+	pub(super) fn path(&self) -> &Path {
+		&self.0
+	}
+
+	/// Creates the file with owner-only permissions before anyone writes to
+	/// it, refusing to follow or clobber something already at this path
+	pub(super) async fn reserve(&self) -> Result<()> {
+		let mut options = tokio::fs::OpenOptions::new();
+		options.write(true).create_new(true);
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::OpenOptionsExt;
+			options.mode(0o600);
+		}
+		options.open(&self.0).await?;
+		Ok(())
+	}
+}
+
+impl Drop for TempPlaintext {
+	fn drop(&mut self) {
+		let _res = std::fs::remove_file(&self.0);
+	}
+}
+
+/// Reads into `buf` until it's full or the reader is exhausted, unlike a bare
+/// `read`, which may return fewer bytes than asked for
+async fn read_fill(reader: &mut (impl AsyncRead + Unpin), buf: &mut [u8]) -> Result<usize> {
+	let mut filled = 0;
+	while filled < buf.len() {
+		let read = reader.read(&mut buf[filled..]).await?;
+		if read == 0 {
+			break;
+		}
+		filled += read;
+	}
+	Ok(filled)
+}
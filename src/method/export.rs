@@ -0,0 +1,110 @@
+use crate::method::crypt;
+use crate::method::Method;
+use crate::param::Param;
+use crate::Connection;
+use crate::ExtractRouter;
+use crate::Result;
+use crate::Router;
+use crate::Surreal;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::path::PathBuf;
+use std::pin::Pin;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Exports the current namespace/database to `file` as a `.sql` dump
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// db.export("backup.sql").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn export(&self, file: impl Into<PathBuf>) -> Export<'_, C> {
+		Export {
+			router: self.router.extract(),
+			file: file.into(),
+			passphrase: None,
+		}
+	}
+}
+
+/// A database export future
+#[derive(Debug)]
+pub struct Export<'r, C: Connection> {
+	router: Result<&'r Router<C>>,
+	file: PathBuf,
+	passphrase: Option<String>,
+}
+
+impl<'r, C> Export<'r, C>
+where
+	C: Connection,
+{
+	/// Encrypts the dump with a key derived from `passphrase` instead of
+	/// writing it out in plain text
+	///
+	/// The dump is still produced as a plain `.sql` file first -- the
+	/// embedded router only takes a destination path, not a writer it could
+	/// pipe straight into the cipher -- then streamed through an
+	/// authenticated cipher chunk by chunk into `file`. The intermediate
+	/// file is given an unpredictable name and owner-only permissions, and
+	/// is removed as soon as this future finishes, whether it succeeds or
+	/// not. See [`Import::with_passphrase`](super::import::Import::with_passphrase)
+	/// for the reverse direction.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// db.export("backup.sql.enc").with_passphrase("hunter2").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn with_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+		self.passphrase = Some(passphrase.into());
+		self
+	}
+}
+
+impl<'r, Client> IntoFuture for Export<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.router?;
+			let Some(passphrase) = self.passphrase else {
+				let mut conn = Client::new(Method::Export);
+				return conn.execute(router, Param::file(self.file)).await;
+			};
+
+			let plain = crypt::TempPlaintext::beside(&self.file);
+			plain.reserve().await?;
+
+			let mut conn = Client::new(Method::Export);
+			conn.execute(router, Param::file(plain.path().to_path_buf())).await?;
+
+			let reader = tokio::fs::File::open(plain.path()).await?;
+			let writer = tokio::fs::File::create(&self.file).await?;
+			crypt::encrypt(&passphrase, reader, writer).await
+		})
+	}
+}
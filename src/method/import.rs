@@ -1,3 +1,4 @@
+use crate::method::crypt;
 use crate::method::Method;
 use crate::param::Param;
 use crate::Connection;
@@ -13,6 +14,41 @@ use std::pin::Pin;
 pub struct Import<'r, C: Connection> {
 	pub(super) router: Result<&'r Router<C>>,
 	pub(super) file: PathBuf,
+	pub(super) passphrase: Option<String>,
+}
+
+impl<'r, C> Import<'r, C>
+where
+	C: Connection,
+{
+	/// Decrypts `file` with a key derived from `passphrase` before importing it
+	///
+	/// Reverses [`Export::with_passphrase`](super::export::Export::with_passphrase):
+	/// `file` is decrypted chunk by chunk into an unpredictably-named,
+	/// owner-only-permissioned temp file -- the embedded router only takes a
+	/// source path, not a reader it could decrypt straight into -- which is
+	/// then imported exactly like a plain dump and removed as soon as this
+	/// future finishes, whether it succeeds or not. A wrong passphrase or a
+	/// tampered file fails loudly with [`ErrorKind::Crypt`](crate::ErrorKind::Crypt)
+	/// rather than importing garbage.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// db.import("backup.sql.enc").with_passphrase("hunter2").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn with_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+		self.passphrase = Some(passphrase.into());
+		self
+	}
 }
 
 impl<'r, Client> IntoFuture for Import<'r, Client>
@@ -23,9 +59,22 @@ where
 	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
 
 	fn into_future(self) -> Self::IntoFuture {
-		Box::pin(async {
+		Box::pin(async move {
+			let router = self.router?;
+			let Some(passphrase) = self.passphrase else {
+				let mut conn = Client::new(Method::Import);
+				return conn.execute(router, Param::file(self.file)).await;
+			};
+
+			let plain = crypt::TempPlaintext::beside(&self.file);
+			plain.reserve().await?;
+
+			let reader = tokio::fs::File::open(&self.file).await?;
+			let writer = tokio::fs::OpenOptions::new().write(true).open(plain.path()).await?;
+			crypt::decrypt(&passphrase, reader, writer).await?;
+
 			let mut conn = Client::new(Method::Import);
-			conn.execute(self.router?, Param::file(self.file)).await
+			conn.execute(router, Param::file(plain.path().to_path_buf())).await
 		})
 	}
 }
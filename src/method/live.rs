@@ -1,33 +1,157 @@
 use crate::method::Method;
 use crate::param::Param;
 use crate::Connection;
+use crate::ErrorKind;
 use crate::Result;
 use crate::Router;
+use futures::stream::Stream;
+use futures::stream::StreamExt;
+use serde::de::DeserializeOwned;
 use std::future::Future;
 use std::future::IntoFuture;
+use std::marker::PhantomData;
 use std::pin::Pin;
-use surrealdb::sql::Table;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
 use surrealdb::sql::Uuid;
 use surrealdb::sql::Value;
 
+/// The kind of change that produced a live query [`Notification`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+	/// The record was created
+	Create,
+	/// The record was updated
+	Update,
+	/// The record was deleted
+	Delete,
+}
+
+/// A single change pushed by a live query
+#[derive(Debug, Clone)]
+pub struct Notification<T> {
+	/// The kind of change that triggered this notification
+	pub action: Action,
+	/// The record affected by the change
+	pub data: T,
+}
+
 /// A live query future
+///
+/// Resolves to a [`Notifications`] stream once the server has confirmed the
+/// subscription. For a WebSocket connection, [`Connection::execute_notifications`]
+/// registers the returned id on [`Router::register_live`] so the read loop
+/// can demultiplex pushed frames to it.
 #[derive(Debug)]
-pub struct Live<'r, C: Connection> {
-	pub(super) router: Result<&'r Router<C>>,
-	pub(super) table_name: String,
+pub struct Live<C: Connection, R> {
+	pub(super) router: Result<Arc<Router<C>>>,
+	pub(super) resource: Result<Value>,
+	pub(super) response_type: PhantomData<R>,
 }
 
-impl<'r, Client> IntoFuture for Live<'r, Client>
+impl<Client, R> IntoFuture for Live<Client, R>
 where
 	Client: Connection,
+	R: DeserializeOwned + Send + Sync + 'static,
 {
-	type Output = Result<Uuid>;
-	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+	type Output = Result<Notifications<Client, R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
+			let router = self.router?;
+			if !router.capabilities()?.live_queries() {
+				return Err(ErrorKind::Unsupported.with_message(format!(
+					"the connected server (version {}) does not support live queries",
+					router.capabilities()?.server_version()
+				)));
+			}
 			let mut conn = Client::new(Method::Live);
-			conn.execute(self.router?, Param::new(vec![Value::Table(Table(self.table_name))])).await
+			let query_id: Uuid =
+				conn.execute(&router, Param::new(vec![self.resource?])).await?;
+			let mut conn = Client::new(Method::Live);
+			let stream = conn
+				.execute_notifications(&router, Param::new(vec![query_id.clone().into()]))
+				.await?;
+			Ok(Notifications {
+				router: Ok(router),
+				query_id,
+				stream,
+			})
 		})
 	}
 }
+
+/// A stream of live query [`Notification`]s
+///
+/// Dropping the stream kills the underlying live query on the server.
+pub struct Notifications<C: Connection, R> {
+	router: Result<Arc<Router<C>>>,
+	query_id: Uuid,
+	stream: Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>,
+}
+
+impl<C, R> std::fmt::Debug for Notifications<C, R>
+where
+	C: Connection,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Notifications").field("query_id", &self.query_id).finish()
+	}
+}
+
+impl<C, R> Notifications<C, R>
+where
+	C: Connection,
+{
+	/// Builds a [`Notifications`] stream around an already-registered live query
+	///
+	/// Used by [`Live`] and by [`Query::live`](crate::method::query::Query::live),
+	/// which registers its live query by executing a raw `LIVE SELECT`
+	/// instead of going through [`Live`]'s resource-based registration.
+	pub(in crate::method) fn new(
+		router: Result<Arc<Router<C>>>,
+		query_id: Uuid,
+		stream: Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>,
+	) -> Self {
+		Self {
+			router,
+			query_id,
+			stream,
+		}
+	}
+
+	/// Returns the id of the underlying live query, for use with [`Kill`](crate::method::Kill)
+	pub fn query_id(&self) -> Uuid {
+		self.query_id.clone()
+	}
+}
+
+impl<C, R> Stream for Notifications<C, R>
+where
+	C: Connection,
+{
+	type Item = Result<Notification<R>>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.stream.poll_next_unpin(cx)
+	}
+}
+
+impl<C, R> Drop for Notifications<C, R>
+where
+	C: Connection,
+{
+	fn drop(&mut self) {
+		if let Ok(router) = self.router.clone() {
+			#[cfg(feature = "ws")]
+			router.unregister_live(&self.query_id);
+			let query_id = self.query_id.clone();
+			tokio::spawn(async move {
+				let mut conn = C::new(Method::Kill);
+				let _: Result<()> = conn.execute(&router, Param::new(vec![query_id.into()])).await;
+			});
+		}
+	}
+}
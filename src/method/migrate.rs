@@ -0,0 +1,319 @@
+use crate::method::Method;
+use crate::param::Param;
+use crate::Connection;
+use crate::ErrorKind;
+use crate::ExtractRouter;
+use crate::Result;
+use crate::Router;
+use crate::Surreal;
+use serde::Deserialize;
+use sha2::Digest;
+use sha2::Sha256;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::path::PathBuf;
+use std::pin::Pin;
+use surrealdb::sql::Value;
+use tokio::fs;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Applies every pending `.surql` migration found in `path`
+	///
+	/// Migration files are expected to be named `<version>_<name>.surql`
+	/// (e.g. `0001_init.surql`) and are applied in ascending `version` order,
+	/// inside a single transaction. Applied versions are recorded in a
+	/// `migration` table together with a hash of their contents, so a
+	/// migration that has already run is never re-applied, and one whose
+	/// contents changed on disk after being applied is refused rather than
+	/// silently re-run.
+	///
+	/// Returns the versions that were newly applied, in the order they ran.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let applied = db.migrate("migrations").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn migrate(&self, path: impl Into<PathBuf>) -> Migrate<'_, C> {
+		Migrate {
+			router: self.router.extract(),
+			path: path.into(),
+		}
+	}
+
+	/// Applies every pending migration in `migrations`
+	///
+	/// Unlike [`migrate`](Self::migrate), which reads `.surql` files from disk
+	/// at run time, this takes migrations that are already in memory -- built
+	/// at run time, or embedded into the binary at compile time with
+	/// [`migration!`]. Versioning, ordering, drift detection, and the
+	/// tracking table are otherwise identical to `migrate`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// use surrealdb_rs::method::migrate::Migration;
+	/// use surrealdb_rs::migration;
+	///
+	/// static MIGRATIONS: &[Migration] = &[
+	///     migration!(1, "init", "../migrations/0001_init.surql"),
+	/// ];
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let applied = db.migrate_embedded(MIGRATIONS).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn migrate_embedded(&self, migrations: impl Into<Vec<Migration>>) -> MigrateEmbedded<'_, C> {
+		MigrateEmbedded {
+			router: self.router.extract(),
+			migrations: migrations.into(),
+		}
+	}
+}
+
+/// A single migration, versioned and named, ready to be applied by
+/// [`Surreal::migrate_embedded`]
+///
+/// `name` and `up` are [`Cow`] so the same type covers both a migration
+/// embedded into the binary at compile time with the [`migration!`] macro
+/// and one built at run time, e.g. read from a config file alongside the
+/// binary.
+#[derive(Debug, Clone)]
+pub struct Migration {
+	/// The migration's version, compared against the `migration` tracking
+	/// table to decide whether it still needs to run
+	pub version: u32,
+	/// A short, human-readable name for the migration
+	pub name: Cow<'static, str>,
+	/// The SurrealQL executed to apply the migration
+	pub up: Cow<'static, str>,
+}
+
+/// Builds a [`Migration`] whose `up` script is embedded into the binary at
+/// compile time with [`include_str!`]
+///
+/// ```no_run
+/// # use surrealdb_rs::migration;
+/// static INIT: surrealdb_rs::method::migrate::Migration =
+///     migration!(1, "init", "../migrations/0001_init.surql");
+/// ```
+///
+/// This embeds one file at a time; there is no macro that globs a whole
+/// `migrations/` directory at compile time, since `macro_rules!` can't read
+/// the filesystem and this crate doesn't ship a proc-macro crate to do it in
+/// a `build.rs`. List the files explicitly in a `&[Migration]`, as shown on
+/// [`Surreal::migrate_embedded`].
+#[macro_export]
+macro_rules! migration {
+	($version:expr, $name:expr, $path:expr) => {
+		$crate::method::migrate::Migration {
+			version: $version,
+			name: std::borrow::Cow::Borrowed($name),
+			up: std::borrow::Cow::Borrowed(include_str!($path)),
+		}
+	};
+}
+
+/// A migration future
+#[derive(Debug)]
+pub struct Migrate<'r, C: Connection> {
+	router: Result<&'r Router<C>>,
+	path: PathBuf,
+}
+
+struct PendingMigration {
+	/// The canonical, zero-padded form of `order`, shared by both entry
+	/// points -- this is what's compared against and stored in the
+	/// `migration` tracking table, so `migrate` and `migrate_embedded`
+	/// recognize each other's rows as the same migration
+	version: String,
+	/// The migration's version as a plain number, used only to sort
+	/// migrations into ascending order before applying them; comparing
+	/// `version` as a string instead would put `"10"` before `"2"`
+	order: u32,
+	name: String,
+	body: String,
+	hash: String,
+}
+
+/// Formats `version` the one way both `migrate` and `migrate_embedded` store
+/// and compare it, so a migration applied through one is recognized by the
+/// other against the same tracking table
+fn canonical_version(version: u32) -> String {
+	format!("{version:04}")
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct AppliedMigration {
+	version: String,
+	hash: String,
+}
+
+impl<'r, Client> IntoFuture for Migrate<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<String>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.router?;
+			let mut migrations = Vec::new();
+			let mut entries = fs::read_dir(&self.path).await?;
+			while let Some(entry) = entries.next_entry().await? {
+				let path = entry.path();
+				if path.extension().and_then(|ext| ext.to_str()) != Some("surql") {
+					continue;
+				}
+				let file_name =
+					path.file_stem().and_then(|name| name.to_str()).ok_or_else(|| {
+						ErrorKind::Migration
+							.with_message(format!("invalid migration file name: {}", path.display()))
+					})?;
+				let (version, name) = file_name.split_once('_').ok_or_else(|| {
+					ErrorKind::Migration.with_message(format!(
+						"migration file name `{file_name}` is missing a `<version>_<name>` prefix"
+					))
+				})?;
+				let order: u32 = version.parse().map_err(|_| {
+					ErrorKind::Migration.with_message(format!(
+						"migration file name `{file_name}` has a non-numeric version `{version}`"
+					))
+				})?;
+				let body = fs::read_to_string(&path).await?;
+				let hash = format!("{:x}", Sha256::digest(body.as_bytes()));
+				migrations.push(PendingMigration {
+					version: canonical_version(order),
+					order,
+					name: name.to_owned(),
+					body,
+					hash,
+				});
+			}
+			migrations.sort_by_key(|migration| migration.order);
+
+			apply_pending(router, migrations).await
+		})
+	}
+}
+
+/// A migration future created by [`Surreal::migrate_embedded`]
+#[derive(Debug)]
+pub struct MigrateEmbedded<'r, C: Connection> {
+	router: Result<&'r Router<C>>,
+	migrations: Vec<Migration>,
+}
+
+impl<'r, Client> IntoFuture for MigrateEmbedded<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<String>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.router?;
+			let mut migrations: Vec<PendingMigration> = self
+				.migrations
+				.iter()
+				.map(|migration| PendingMigration {
+					version: canonical_version(migration.version),
+					order: migration.version,
+					name: migration.name.clone().into_owned(),
+					body: migration.up.clone().into_owned(),
+					hash: format!("{:x}", Sha256::digest(migration.up.as_bytes())),
+				})
+				.collect();
+			migrations.sort_by_key(|migration| migration.order);
+
+			apply_pending(router, migrations).await
+		})
+	}
+}
+
+/// Diffs `migrations` against the `migration` tracking table and applies
+/// whatever hasn't run yet inside a single transaction
+///
+/// Shared by [`Migrate`] and [`MigrateEmbedded`], which only differ in where
+/// their `Vec<PendingMigration>` comes from.
+async fn apply_pending<C: Connection>(
+	router: &Router<C>,
+	migrations: Vec<PendingMigration>,
+) -> Result<Vec<String>> {
+	let mut conn = C::new(Method::Query);
+	let applied = conn
+		.execute_query(
+			router,
+			Param::new(vec!["SELECT version, hash FROM migration ORDER BY version".into()]),
+		)
+		.await?;
+	let applied: Vec<AppliedMigration> = applied.get(0, ..)?;
+	let applied: BTreeMap<String, String> =
+		applied.into_iter().map(|migration| (migration.version, migration.hash)).collect();
+
+	let mut pending = Vec::new();
+	for migration in migrations {
+		match applied.get(&migration.version) {
+			Some(hash) if *hash == migration.hash => continue,
+			Some(_) => {
+				return Err(ErrorKind::Migration.with_message(format!(
+					"migration {} has already been applied but its contents changed on disk",
+					migration.version
+				)));
+			}
+			None => pending.push(migration),
+		}
+	}
+
+	if pending.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let mut statements = String::from("BEGIN TRANSACTION;\n");
+	let mut bindings = BTreeMap::new();
+	for (index, migration) in pending.iter().enumerate() {
+		statements.push_str(&migration.body);
+		statements.push_str(&format!(
+			"\nCREATE migration SET version = $migration_version_{index}, \
+			 name = $migration_name_{index}, hash = $migration_hash_{index}, \
+			 applied_at = time::now();\n"
+		));
+		bindings.insert(format!("migration_version_{index}"), Value::from(migration.version.clone()));
+		bindings.insert(format!("migration_name_{index}"), Value::from(migration.name.clone()));
+		bindings.insert(format!("migration_hash_{index}"), Value::from(migration.hash.clone()));
+	}
+	statements.push_str("COMMIT TRANSACTION;");
+
+	let mut conn = C::new(Method::Query);
+	let response =
+		conn.execute_query(router, Param::new(vec![statements.into(), bindings.into()])).await?;
+
+	// `execute_query` only reports a transport-level failure; a statement
+	// that failed inside the transaction (including one of our own tracking
+	// `CREATE`s) comes back as an `Err` in its own slot instead, which must
+	// be surfaced here or a failed migration would be reported as applied
+	for result in response.into_inner() {
+		result.into_inner()?;
+	}
+
+	Ok(pending.into_iter().map(|migration| migration.version).collect())
+}
@@ -2,45 +2,58 @@
 /// returned by the database.
 pub mod response;
 
+use crate::method::live::Notifications;
 use crate::method::Method;
 use crate::param;
-use crate::param::from_json;
+use crate::param::ser::to_value;
 use crate::param::Param;
 use crate::Connection;
 use crate::ErrorKind;
 use crate::Result;
 use crate::Router;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::json;
 use std::collections::BTreeMap;
 use std::future::Future;
 use std::future::IntoFuture;
+use std::marker::PhantomData;
 use std::mem;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 use surrealdb::sql;
+use surrealdb::sql::statements::DeleteStatement;
+use surrealdb::sql::statements::SelectStatement;
+use surrealdb::sql::statements::UpdateStatement;
 use surrealdb::sql::Array;
 use surrealdb::sql::Object;
 use surrealdb::sql::Statement;
 use surrealdb::sql::Statements;
 use surrealdb::sql::Strand;
+use surrealdb::sql::Timeout;
+use surrealdb::sql::Uuid;
 use surrealdb::sql::Value;
 
 use response::QueryResponse;
 
 /// A query future
 #[derive(Debug)]
-pub struct Query<'r, C: Connection> {
-	pub(super) router: Result<&'r Router<C>>,
+pub struct Query<C: Connection> {
+	pub(super) router: Result<Arc<Router<C>>>,
 	pub(super) query: Vec<Result<Vec<Statement>>>,
 	pub(super) bindings: Result<BTreeMap<String, Value>>,
+	pub(super) timeout: Option<Duration>,
+	pub(super) parallel: bool,
+	pub(super) with_stats: bool,
 }
 
-impl<'r, Client> IntoFuture for Query<'r, Client>
+impl<Client> IntoFuture for Query<Client>
 where
 	Client: Connection,
 {
 	type Output = Result<QueryResponse>;
-	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
@@ -48,18 +61,56 @@ where
 			for query in self.query {
 				statements.extend(query?);
 			}
+			for statement in &mut statements {
+				apply_options(statement, self.timeout, self.parallel);
+			}
 			let mut param = vec![sql::Query(Statements(statements)).to_string().into()];
 			let bindings = self.bindings?;
 			if !bindings.is_empty() {
 				param.push(bindings.into());
 			}
+			let router = self.router?;
 			let mut conn = Client::new(Method::Query);
-			conn.execute_query(self.router?, Param::new(param)).await
+			let with_stats = self.with_stats;
+			let start = Instant::now();
+			let response = conn.execute_query(&router, Param::new(param)).await?;
+			Ok(match with_stats {
+				true => response.with_stats(start.elapsed()),
+				false => response,
+			})
 		})
 	}
 }
 
-impl<'r, C> Query<'r, C>
+/// Mutates `statement` in place to carry the given `TIMEOUT`/`PARALLEL` clauses,
+/// leaving statements that cannot carry them untouched
+fn apply_options(statement: &mut Statement, timeout: Option<Duration>, parallel: bool) {
+	match statement {
+		Statement::Select(SelectStatement {
+			timeout: stmt_timeout,
+			parallel: stmt_parallel,
+			..
+		})
+		| Statement::Update(UpdateStatement {
+			timeout: stmt_timeout,
+			parallel: stmt_parallel,
+			..
+		})
+		| Statement::Delete(DeleteStatement {
+			timeout: stmt_timeout,
+			parallel: stmt_parallel,
+			..
+		}) => {
+			if let Some(timeout) = timeout {
+				*stmt_timeout = Some(Timeout(timeout.into()));
+			}
+			*stmt_parallel = *stmt_parallel || parallel;
+		}
+		_ => {}
+	}
+}
+
+impl<C> Query<C>
 where
 	C: Connection,
 {
@@ -113,7 +164,13 @@ where
 	/// ```
 	pub fn bind(mut self, bindings: impl Serialize) -> Self {
 		if let Ok(current) = &mut self.bindings {
-			let mut bindings = from_json(json!(bindings));
+			let mut bindings = match to_value(bindings) {
+				Ok(bindings) => bindings,
+				Err(error) => {
+					self.bindings = Err(error);
+					return self;
+				}
+			};
 			if let Value::Array(Array(array)) = &mut bindings {
 				if let [Value::Strand(Strand(key)), value] = &mut array[..] {
 					let mut map = BTreeMap::new();
@@ -130,4 +187,126 @@ where
 		}
 		self
 	}
+
+	/// Appends a `TIMEOUT` clause to statements that support it
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// Appends a `PARALLEL` clause to statements that support it
+	pub fn parallel(mut self, parallel: bool) -> Self {
+		self.parallel = parallel;
+		self
+	}
+
+	/// Requests that the client record how long this query's whole round
+	/// trip took, retrievable afterwards with [`QueryResponse::elapsed`]
+	///
+	/// This is measured client-side around the whole request, not decoded
+	/// per-statement from the response envelope -- the server doesn't
+	/// report timing for individual statements in a multi-statement query,
+	/// so there's only one figure for the whole batch.
+	pub fn with_stats(mut self, with_stats: bool) -> Self {
+		self.with_stats = with_stats;
+		self
+	}
+}
+
+impl<C> Query<C>
+where
+	C: Connection,
+{
+	/// Turns this query into a live query
+	///
+	/// The query must consist of a single `LIVE SELECT` statement, whose id
+	/// the server returns as the query's only result. That id is then used
+	/// to subscribe to the [`Notification`](crate::method::live::Notification)s
+	/// the `LIVE SELECT` pushes, exactly like
+	/// [`Select::live`](crate::method::select::Select::live)
+	/// does for a plain resource; dropping the returned stream issues a
+	/// [`Kill`](crate::method::Kill) for it automatically.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// use futures::StreamExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let mut stream = db.query("LIVE SELECT * FROM person WHERE age >= 18").live::<serde_json::Value>().await?;
+	/// while let Some(notification) = stream.next().await {
+	///     let notification = notification?;
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn live<R>(self) -> LiveQuery<C, R>
+	where
+		R: DeserializeOwned + Send + Sync + 'static,
+	{
+		LiveQuery {
+			router: self.router,
+			query: self.query,
+			bindings: self.bindings,
+			response_type: PhantomData,
+		}
+	}
+}
+
+/// A query turned into a live query by [`Query::live`]
+#[derive(Debug)]
+pub struct LiveQuery<C: Connection, R> {
+	router: Result<Arc<Router<C>>>,
+	query: Vec<Result<Vec<Statement>>>,
+	bindings: Result<BTreeMap<String, Value>>,
+	response_type: PhantomData<R>,
+}
+
+impl<Client, R> IntoFuture for LiveQuery<Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned + Send + Sync + 'static,
+{
+	type Output = Result<Notifications<Client, R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.router?;
+			if !router.capabilities()?.live_queries() {
+				return Err(ErrorKind::Unsupported.with_message(format!(
+					"the connected server (version {}) does not support live queries",
+					router.capabilities()?.server_version()
+				)));
+			}
+			let mut statements = Vec::with_capacity(self.query.len());
+			for query in self.query {
+				statements.extend(query?);
+			}
+			if statements.len() != 1 {
+				return Err(ErrorKind::Query.with_context(format!(
+					"a live query must consist of a single statement, found {}",
+					statements.len()
+				)));
+			}
+			let mut param = vec![sql::Query(Statements(statements)).to_string().into()];
+			let bindings = self.bindings?;
+			if !bindings.is_empty() {
+				param.push(bindings.into());
+			}
+			let mut conn = Client::new(Method::Query);
+			let response = conn.execute_query(&router, Param::new(param)).await?;
+			let query_id: Uuid = response.get(0, 0)?;
+
+			let mut conn = Client::new(Method::Live);
+			let stream = conn
+				.execute_notifications(&router, Param::new(vec![query_id.clone().into()]))
+				.await?;
+			Ok(Notifications::new(Ok(router), query_id, stream))
+		})
+	}
 }
@@ -2,13 +2,17 @@ use std::ops::Deref;
 use std::ops::DerefMut;
 use std::ops::Index;
 use std::slice::SliceIndex;
+use std::time::Duration;
 
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::param::from_serializable;
+use crate::ErrorKind;
 use crate::Result;
 use crate::Value;
+use surrealdb::sql::Array;
+use surrealdb::sql::Object;
 
 /// A wrapper type around the list of results for the queries that were returned
 /// by the database.
@@ -16,25 +20,51 @@ use crate::Value;
 /// Provides utility functions to access the result of one specific query, or if
 /// needed, all queries at once.
 ///
-#[derive(Debug, Clone)]
-pub struct QueryResponse(Vec<QueryResult>);
+#[derive(Debug, Clone, Default)]
+pub struct QueryResponse {
+	results: Vec<QueryResult>,
+	stats: Option<Duration>,
+}
 
 impl QueryResponse {
 	/// Constructs an empty [`QueryResponse`]
 	#[allow(unused)]
 	pub(crate) fn new() -> Self {
-		Self(Default::default())
+		Self::default()
+	}
+
+	/// Records the elapsed round-trip time for this response
+	///
+	/// Set when the originating query was built with [`Query::with_stats`](super::Query::with_stats).
+	pub(crate) fn with_stats(mut self, stats: Duration) -> Self {
+		self.stats = Some(stats);
+		self
 	}
 
 	/// Unwrap into the inner list of query results
 	pub fn into_inner(self) -> Vec<QueryResult> {
-		self.0
+		self.results
 	}
 
 	/// Returns a reference the result for the `n`-th query from the response. If
 	/// no result is found at this index then [None] is returned.
 	pub fn query_result(&self, n: usize) -> Option<&QueryResult> {
-		self.0.get(n)
+		self.results.get(n)
+	}
+
+	/// Returns the elapsed wall-clock time for the whole round trip this
+	/// response came from -- from submitting the query to decoding the
+	/// reply -- timed client-side, not a per-statement figure read out of
+	/// the response envelope
+	///
+	/// A multi-statement query only gets one measurement for the whole
+	/// batch, since the server doesn't report timing per statement; there
+	/// is no way to tell how much of it any one statement accounted for.
+	///
+	/// Returns [`None`] if the query wasn't built with
+	/// [`Query::with_stats`](super::Query::with_stats).
+	pub fn elapsed(&self) -> Option<Duration> {
+		self.stats
 	}
 
 	/// Returns the deserialized `<T>` from the inner [Value]s over the given
@@ -89,11 +119,198 @@ impl QueryResponse {
 			Some(query_result) => query_result.get(index_or_range),
 		}
 	}
+
+	/// Deserializes every statement's result into a typed tuple in one call
+	///
+	/// # Examples
+	/// ```no_run
+	/// # #[derive(Debug, serde::Deserialize, Default)]
+	/// # struct Account {
+	/// #   id: String,
+	/// #   balance: String
+	/// # }
+	/// #
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let client = Surreal::<WsClient>::new();
+	/// let response = client
+	///     .query("CREATE account:one SET balance = 0")
+	///     .query("SELECT * FROM account")
+	///     .await?;
+	///
+	/// let (created, accounts): (Account, Vec<Account>) = response.take()?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn take<T>(self) -> Result<T>
+	where
+		T: FromQueryResponse,
+	{
+		T::from_query_response(self)
+	}
+
+	/// Deserializes a single row's fields into a typed tuple by position, without naming them
+	///
+	/// Unlike [`get`](Self::get), which deserializes a whole item into a type
+	/// implementing [`DeserializeOwned`], this pulls each tuple element out of
+	/// the row's own fields -- the `n`-th field of an object-shaped row, or
+	/// the `n`-th element of an array-shaped one.
+	///
+	/// For an array-shaped row this really is positional in the order you
+	/// wrote it. For an object-shaped row -- e.g. a plain `SELECT name, age
+	/// FROM user` -- it isn't: fields come back in the object's own key
+	/// order (alphabetical), not the order they were named in the
+	/// statement, so `(String, u32)` against `{name, age}` would actually
+	/// receive `age` first. To get a guaranteed field order out of a
+	/// `SELECT`, build the row as an array explicitly with `VALUE`, as below.
+	///
+	/// # Examples
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let client = Surreal::<WsClient>::new();
+	/// let response = client.query("SELECT VALUE [name, age] FROM user LIMIT 1").await?;
+	///
+	/// let (name, age): (String, u32) = response.row(0, 0)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn row<T>(&self, query_index: usize, index: usize) -> Result<T>
+	where
+		T: FromRow,
+	{
+		match self.query_result(query_index) {
+			None => Err(ErrorKind::Query
+				.with_context(format!("no query found at index {query_index}"))),
+			Some(query_result) => query_result.row(index),
+		}
+	}
+}
+
+/// Trait for extracting the whole result of a multi-statement query into a typed tuple
+///
+/// Implemented for tuples of up to twelve elements, where the `n`-th element is
+/// deserialized from the `n`-th statement of the query. A statement that
+/// returned exactly one row is deserialized from that row if the element's
+/// type accepts it, and from the list of rows otherwise.
+pub trait FromQueryResponse: Sized {
+	/// Builds `Self` out of a whole [`QueryResponse`], consuming it
+	fn from_query_response(response: QueryResponse) -> Result<Self>;
+}
+
+/// Deserializes the result of the statement at `index` for [`FromQueryResponse`]
+fn take_statement<T>(response: &QueryResponse, index: usize) -> Result<T>
+where
+	T: DeserializeOwned + Default,
+{
+	if let Some(Ok(values)) = response.query_result(index).map(|result| result.as_ref()) {
+		if let [value] = &values[..] {
+			if let Ok(value) = from_serializable(value) {
+				return Ok(value);
+			}
+		}
+	}
+	response.get(index, ..)
+}
+
+macro_rules! impl_from_query_response_for_tuple {
+	($count:literal; $($ty:ident : $idx:tt),+) => {
+		impl<$($ty),+> FromQueryResponse for ($($ty,)+)
+		where
+			$($ty: DeserializeOwned + Default,)+
+		{
+			fn from_query_response(response: QueryResponse) -> Result<Self> {
+				if response.len() != $count {
+					return Err(ErrorKind::Query.with_context(format!(
+						"expected a query response with {} statement(s), found {}",
+						$count,
+						response.len()
+					)));
+				}
+				Ok(($(take_statement::<$ty>(&response, $idx)?,)+))
+			}
+		}
+	};
+}
+
+impl_from_query_response_for_tuple!(1; A:0);
+impl_from_query_response_for_tuple!(2; A:0, B:1);
+impl_from_query_response_for_tuple!(3; A:0, B:1, C:2);
+impl_from_query_response_for_tuple!(4; A:0, B:1, C:2, D:3);
+impl_from_query_response_for_tuple!(5; A:0, B:1, C:2, D:3, E:4);
+impl_from_query_response_for_tuple!(6; A:0, B:1, C:2, D:3, E:4, F:5);
+impl_from_query_response_for_tuple!(7; A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+impl_from_query_response_for_tuple!(8; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+impl_from_query_response_for_tuple!(9; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8);
+impl_from_query_response_for_tuple!(10; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9);
+impl_from_query_response_for_tuple!(11; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10);
+impl_from_query_response_for_tuple!(12; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11);
+
+/// Trait for extracting a single row's fields positionally, without naming them
+///
+/// Implemented for tuples of up to twelve elements, where the `n`-th element
+/// is pulled from the `n`-th field of an object-shaped [`Value`] (in the
+/// object's key order) or the `n`-th element of an array-shaped one. Used by
+/// [`QueryResponse::row`]/[`QueryResult::row`]; named structs keep going
+/// through the existing [`DeserializeOwned`]-based [`get`](QueryResponse::get).
+pub trait FromRow: Sized {
+	/// Builds `Self` out of a single row's [`Value`]
+	fn from_value(value: &Value) -> Result<Self>;
+}
+
+/// Returns `value`'s fields in order, whether it's an object or an array
+fn row_fields(value: &Value) -> Result<Vec<&Value>> {
+	match value {
+		Value::Object(Object(map)) => Ok(map.values().collect()),
+		Value::Array(Array(vec)) => Ok(vec.iter().collect()),
+		_ => Err(ErrorKind::Query.with_context("expected an object or array row, found a scalar value")),
+	}
+}
+
+macro_rules! impl_from_row_for_tuple {
+	($count:literal; $($ty:ident : $idx:tt),+) => {
+		impl<$($ty),+> FromRow for ($($ty,)+)
+		where
+			$($ty: DeserializeOwned,)+
+		{
+			fn from_value(value: &Value) -> Result<Self> {
+				let fields = row_fields(value)?;
+				if fields.len() != $count {
+					return Err(ErrorKind::Query.with_context(format!(
+						"expected a row with {} field(s), found {}",
+						$count,
+						fields.len()
+					)));
+				}
+				Ok(($(from_serializable(fields[$idx])?,)+))
+			}
+		}
+	};
 }
 
+impl_from_row_for_tuple!(1; A:0);
+impl_from_row_for_tuple!(2; A:0, B:1);
+impl_from_row_for_tuple!(3; A:0, B:1, C:2);
+impl_from_row_for_tuple!(4; A:0, B:1, C:2, D:3);
+impl_from_row_for_tuple!(5; A:0, B:1, C:2, D:3, E:4);
+impl_from_row_for_tuple!(6; A:0, B:1, C:2, D:3, E:4, F:5);
+impl_from_row_for_tuple!(7; A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+impl_from_row_for_tuple!(8; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+impl_from_row_for_tuple!(9; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8);
+impl_from_row_for_tuple!(10; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9);
+impl_from_row_for_tuple!(11; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10);
+impl_from_row_for_tuple!(12; A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11);
+
 impl From<Vec<QueryResult>> for QueryResponse {
 	fn from(vec: Vec<QueryResult>) -> Self {
-		Self(vec)
+		Self {
+			results: vec,
+			stats: None,
+		}
 	}
 }
 
@@ -113,13 +330,13 @@ impl Deref for QueryResponse {
 	type Target = [QueryResult];
 
 	fn deref(&self) -> &Self::Target {
-		&self.0[..]
+		&self.results[..]
 	}
 }
 
 impl DerefMut for QueryResponse {
 	fn deref_mut(&mut self) -> &mut Self::Target {
-		&mut self.0[..]
+		&mut self.results[..]
 	}
 }
 
@@ -128,7 +345,7 @@ impl IntoIterator for QueryResponse {
 	type IntoIter = <Vec<QueryResult> as IntoIterator>::IntoIter;
 
 	fn into_iter(self) -> Self::IntoIter {
-		self.0.into_iter()
+		self.results.into_iter()
 	}
 }
 
@@ -136,13 +353,13 @@ impl Index<usize> for QueryResponse {
 	type Output = QueryResult;
 
 	fn index(&self, index: usize) -> &Self::Output {
-		&self.0[index]
+		&self.results[index]
 	}
 }
 
 impl AsRef<Vec<QueryResult>> for QueryResponse {
 	fn as_ref(&self) -> &Vec<QueryResult> {
-		&self.0
+		&self.results
 	}
 }
 
@@ -225,6 +442,20 @@ impl QueryResult {
 	pub fn into_inner(self) -> Result<Vec<Value>> {
 		self.0
 	}
+
+	/// Deserializes the row at `index`'s fields into a typed tuple by position
+	///
+	/// See [`QueryResponse::row`] for details and an example.
+	pub fn row<T>(&self, index: usize) -> Result<T>
+	where
+		T: FromRow,
+	{
+		let values: &Vec<Value> = self.0.as_ref().map_err(|error| error.clone())?;
+		let value = values
+			.get(index)
+			.ok_or_else(|| ErrorKind::Query.with_context(format!("no row found at index {index}")))?;
+		T::from_value(value)
+	}
 }
 
 impl From<Result<Vec<Value>>> for QueryResult {
@@ -0,0 +1,195 @@
+use crate::method::live::Live;
+use crate::method::Method;
+use crate::param::Param;
+use crate::Connection;
+use crate::Result;
+use crate::Router;
+use futures::stream::Stream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::ops::Bound;
+use std::pin::Pin;
+use std::sync::Arc;
+use surrealdb::sql::Value;
+
+/// A select future
+#[derive(Debug)]
+pub struct Select<C: Connection, R> {
+	pub(super) router: Result<Arc<Router<C>>>,
+	pub(super) resource: Result<Value>,
+	pub(super) range: Option<(Bound<Value>, Bound<Value>)>,
+	pub(super) limit: Option<u64>,
+	pub(super) start: Option<u64>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C: Connection, R> Clone for Select<C, R> {
+	fn clone(&self) -> Self {
+		Self {
+			router: self.router.clone(),
+			resource: self.resource.clone(),
+			range: self.range.clone(),
+			limit: self.limit,
+			start: self.start,
+			response_type: PhantomData,
+		}
+	}
+}
+
+impl<Client, R> IntoFuture for Select<Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<R>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut params = vec![self.resource?];
+			if let Some(range) = self.range {
+				params.push(range.0.into());
+				params.push(range.1.into());
+			}
+			if let Some(limit) = self.limit {
+				params.push(limit.into());
+			}
+			if let Some(start) = self.start {
+				params.push(start.into());
+			}
+			let router = self.router?;
+			let mut conn = Client::new(Method::Select);
+			conn.execute(&router, Param::new(params)).await
+		})
+	}
+}
+
+impl<C, R> Select<C, R>
+where
+	C: Connection,
+{
+	/// Restricts the selection to a range of record ids
+	pub fn range<B, T>(mut self, range: B) -> Self
+	where
+		B: std::ops::RangeBounds<T>,
+		T: Into<Value> + Clone,
+	{
+		let map_bound = |bound: Bound<&T>| match bound {
+			Bound::Included(value) => Bound::Included(value.clone().into()),
+			Bound::Excluded(value) => Bound::Excluded(value.clone().into()),
+			Bound::Unbounded => Bound::Unbounded,
+		};
+		self.range = Some((map_bound(range.start_bound()), map_bound(range.end_bound())));
+		self
+	}
+
+	/// Limits the number of records returned by the selection
+	pub fn limit(mut self, limit: u64) -> Self {
+		self.limit = Some(limit);
+		self
+	}
+
+	/// Skips the first `start` records of the selection
+	pub fn start(mut self, start: u64) -> Self {
+		self.start = Some(start);
+		self
+	}
+
+	/// Rewrites the lower bound of the range so it excludes everything up to
+	/// and including `key`, for keyset pagination that stays stable under
+	/// concurrent inserts
+	pub fn after(mut self, key: impl Into<Value>) -> Self {
+		let upper = self.range.take().map(|(_, upper)| upper).unwrap_or(Bound::Unbounded);
+		self.range = Some((Bound::Excluded(key.into()), upper));
+		self
+	}
+
+	/// Rewrites the upper bound of the range so it excludes everything from
+	/// `key` onwards
+	pub fn before(mut self, key: impl Into<Value>) -> Self {
+		let lower = self.range.take().map(|(lower, _)| lower).unwrap_or(Bound::Unbounded);
+		self.range = Some((lower, Bound::Excluded(key.into())));
+		self
+	}
+}
+
+impl<C, R> Select<C, R>
+where
+	C: Connection,
+	R: DeserializeOwned + Send + Sync + 'static,
+{
+	/// Turns this selection into a live query
+	///
+	/// Instead of resolving once, the returned future resolves to a
+	/// `Stream` of [`Notification`](crate::method::live::Notification)s
+	/// pushed by the server whenever a record matching this selection's
+	/// resource is created, updated, or deleted. Pagination set via
+	/// [`range`](Self::range), [`limit`](Self::limit), or
+	/// [`start`](Self::start) doesn't apply to live queries and is dropped.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// use futures::StreamExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let mut stream = db.select("person").live().await?;
+	/// while let Some(notification) = stream.next().await {
+	///     let notification = notification?;
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn live(self) -> Live<C, R> {
+		Live {
+			router: self.router,
+			resource: self.resource,
+			response_type: PhantomData,
+		}
+	}
+}
+
+impl<C, T> Select<C, Vec<T>>
+where
+	C: Connection,
+	T: DeserializeOwned + Serialize + Send + Sync + 'static,
+{
+	/// Walks an entire range in fixed-size pages
+	///
+	/// Repeatedly issues the range query, advancing the cursor with
+	/// [`after`](Self::after) to the last id of each page, until a page
+	/// returns fewer than `page_size` rows.
+	pub fn pages(
+		self,
+		page_size: u64,
+	) -> Pin<Box<dyn Stream<Item = Result<Vec<T>>> + Send + 'static>> {
+		let select = self.limit(page_size);
+		Box::pin(futures::stream::unfold(Some(select), move |state| async move {
+			let select = state?;
+			let page = select.clone().into_future().await;
+			let page = match page {
+				Ok(page) => page,
+				Err(error) => return Some((Err(error), None)),
+			};
+			let next = match page.len() as u64 == page_size {
+				true => last_id(&page).map(|id| select.after(id)),
+				false => None,
+			};
+			Some((Ok(page), next))
+		}))
+	}
+}
+
+fn last_id<T: Serialize>(page: &[T]) -> Option<Value> {
+	let last = page.last()?;
+	let value = serde_json::to_value(last).ok()?;
+	let id = value.get("id")?;
+	Some(crate::param::from_json(id.clone()))
+}
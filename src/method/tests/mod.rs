@@ -5,16 +5,20 @@ mod protocol;
 mod server;
 mod types;
 
+use crate::method::live::Notification;
 use crate::param::PatchOp;
 use crate::QueryResponse;
+use crate::Result;
 use crate::StaticClient;
 use crate::Surreal;
+use futures::StreamExt;
 use protocol::Client;
 use protocol::Test;
 use semver::Version;
 use std::ops::Bound;
 use surrealdb::sql::statements::BeginStatement;
 use surrealdb::sql::statements::CommitStatement;
+use surrealdb::sql::Value;
 use types::User;
 use types::USER;
 
@@ -35,7 +39,7 @@ async fn api() {
 	let _: QueryResponse = DB.query("SELECT * FROM user").await.unwrap();
 	let _: QueryResponse =
 		DB.query("CREATE user:john SET name = $name").bind("name", "John Doe").await.unwrap();
-	let _: QueryResponse = DB
+	let response: QueryResponse = DB
 		.query(BeginStatement)
 		.query("CREATE account:one SET balance = 135605.16")
 		.query("CREATE account:two SET balance = 91031.31")
@@ -45,6 +49,9 @@ async fn api() {
 		.await
 		.unwrap();
 
+	// take a whole multi-statement response apart in one call
+	let _: (Value, Value, Value, Value, Value, Value) = response.take().unwrap();
+
 	// create
 	let _: User = DB.create(USER).await.unwrap();
 	let _: User = DB.create((USER, "john")).await.unwrap();
@@ -63,6 +70,11 @@ async fn api() {
 	let _: Vec<User> = DB.select(USER).range("jane"..="john").await.unwrap();
 	let _: Vec<User> =
 		DB.select(USER).range((Bound::Excluded("jane"), Bound::Included("john"))).await.unwrap();
+	let _: Vec<User> = DB.select(USER).range("jane"..).start(10).limit(20).await.unwrap();
+	let _: Vec<User> = DB.select(USER).range("jane"..).after("john").await.unwrap();
+	let _: Vec<User> = DB.select(USER).range(.."john").before("jane").await.unwrap();
+	let mut pages = DB.select(USER).range(..).pages(20);
+	let _: Option<Result<Vec<User>>> = pages.next().await;
 
 	// update
 	let _: Vec<User> = DB.update(USER).await.unwrap();
@@ -90,6 +102,12 @@ async fn api() {
 
 	// version
 	let _: Version = DB.version().await.unwrap();
+
+	// live
+	let mut stream = DB.select(USER).live().await.unwrap();
+	let _: Option<Notification<User>> = stream.next().await;
+	let mut stream = DB.select((USER, "john")).live().await.unwrap();
+	let _: Option<Notification<User>> = stream.next().await;
 }
 
 fn send_and_sync(_: impl Send + Sync) {}
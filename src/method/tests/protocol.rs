@@ -1,4 +1,5 @@
 use super::server;
+use crate::method::live::Notification;
 use crate::method::query_response::QueryResponse;
 use crate::param::from_value;
 use crate::param::DbResponse;
@@ -12,14 +13,20 @@ use crate::Route;
 use crate::Router;
 use crate::Surreal;
 use flume::Receiver;
+use futures::stream::Stream;
+use futures::stream::StreamExt;
 use once_cell::sync::OnceCell;
 use serde::de::DeserializeOwned;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
 #[cfg(feature = "ws")]
+use std::collections::HashMap;
+#[cfg(feature = "ws")]
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
+#[cfg(feature = "ws")]
+use std::sync::Mutex;
 use url::Url;
 
 #[derive(Debug)]
@@ -64,10 +71,15 @@ impl Connection for Client {
 				sender: route_tx,
 				#[cfg(feature = "ws")]
 				last_id: AtomicI64::new(0),
+				#[cfg(feature = "ws")]
+				live_senders: Mutex::new(HashMap::new()),
+				capabilities: OnceCell::new(),
+				transaction_lock: tokio::sync::Mutex::new(()),
 			};
 			server::mock(route_rx);
 			Ok(Surreal {
 				router: OnceCell::with_value(Arc::new(router)),
+				authenticator: OnceCell::new(),
 			})
 		})
 	}
@@ -105,7 +117,7 @@ impl Connection for Client {
 			let result = rx.into_recv_async().await.unwrap();
 			match result.unwrap() {
 				DbResponse::Other(value) => from_value(&value),
-				DbResponse::Query(..) => unreachable!(),
+				DbResponse::Query(..) | DbResponse::Notification(..) => unreachable!(),
 			}
 		})
 	}
@@ -118,8 +130,24 @@ impl Connection for Client {
 			let result = rx.into_recv_async().await.unwrap();
 			match result.unwrap() {
 				DbResponse::Query(results) => Ok(results),
-				DbResponse::Other(..) => unreachable!(),
+				DbResponse::Other(..) | DbResponse::Notification(..) => unreachable!(),
 			}
 		})
 	}
+
+	fn recv_notifications<R>(
+		&mut self,
+		rx: Receiver<Self::Response>,
+	) -> Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>
+	where
+		R: DeserializeOwned + Send + Sync + 'static,
+	{
+		Box::pin(rx.into_stream().map(|result| match result? {
+			DbResponse::Notification(notification) => Ok(Notification {
+				action: notification.action,
+				data: from_value(&notification.data)?,
+			}),
+			DbResponse::Other(..) | DbResponse::Query(..) => unreachable!(),
+		}))
+	}
 }
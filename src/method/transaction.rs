@@ -0,0 +1,116 @@
+use crate::Connection;
+use crate::ExtractRouter;
+use crate::Result;
+use crate::Surreal;
+use futures::future::FutureExt;
+use std::fmt;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use surrealdb::sql::statements::BeginStatement;
+use surrealdb::sql::statements::CancelStatement;
+use surrealdb::sql::statements::CommitStatement;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Runs `f` inside a `BEGIN`/`COMMIT` transaction
+	///
+	/// `f` is handed a `tx: Surreal<C>` that shares this client's connection,
+	/// so any `create`/`update`/`delete`/`query` it runs happens inside the
+	/// transaction. If `f` returns `Ok`, the transaction is committed with a
+	/// `COMMIT` statement, as the [`Commit`](super::Commit) future does; if it returns `Err`, or
+	/// panics, a `CANCEL` is issued to roll the transaction back before the
+	/// error (or panic) is propagated. This replaces chaining
+	/// [`BeginStatement`]/[`CommitStatement`] through [`query`](Self::query)
+	/// by hand with a scope that can contain arbitrary control flow.
+	///
+	/// `BEGIN`/`COMMIT` is session state on the shared connection, not
+	/// something the protocol scopes per caller, so this holds a lock on
+	/// the underlying router for the duration of the transaction -- a
+	/// second `.transaction()` call sharing this connection (directly or
+	/// through a cloned client) waits for the first one to commit or cancel
+	/// before its own `BEGIN` goes out, instead of interleaving statements
+	/// inside the same transaction.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// db.transaction(|tx| async move {
+	///     tx.query("UPDATE account:one SET balance -= 300.00").await?;
+	///     tx.query("UPDATE account:two SET balance += 300.00").await?;
+	///     Ok(())
+	/// })
+	/// .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn transaction<F, Fut, R>(&self, f: F) -> Transaction<C, F>
+	where
+		F: FnOnce(Surreal<C>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<R>> + Send + Sync + 'static,
+		R: Send + Sync + 'static,
+	{
+		Transaction {
+			client: self.clone(),
+			f,
+		}
+	}
+}
+
+/// A transaction future created by [`Surreal::transaction`]
+pub struct Transaction<C: Connection, F> {
+	client: Surreal<C>,
+	f: F,
+}
+
+impl<C, F> fmt::Debug for Transaction<C, F>
+where
+	C: Connection,
+{
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		formatter.debug_struct("Transaction").finish_non_exhaustive()
+	}
+}
+
+impl<C, F, Fut, R> IntoFuture for Transaction<C, F>
+where
+	C: Connection,
+	F: FnOnce(Surreal<C>) -> Fut + Send + Sync + 'static,
+	Fut: Future<Output = Result<R>> + Send + Sync + 'static,
+	R: Send + Sync + 'static,
+{
+	type Output = Result<R>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'static>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let _lock = router.transaction_lock.lock().await;
+
+			self.client.query(BeginStatement).await?;
+			let tx = self.client.clone();
+			match AssertUnwindSafe((self.f)(tx)).catch_unwind().await {
+				Ok(Ok(value)) => {
+					self.client.query(CommitStatement).await?;
+					Ok(value)
+				}
+				Ok(Err(error)) => {
+					let _res = self.client.query(CancelStatement).await;
+					Err(error)
+				}
+				Err(panic) => {
+					let _res = self.client.query(CancelStatement).await;
+					std::panic::resume_unwind(panic);
+				}
+			}
+		})
+	}
+}
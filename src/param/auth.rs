@@ -0,0 +1,31 @@
+use crate::param::Jwt;
+use crate::Result;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Supplies the credentials a connection signs in with, modeled on Scylla's
+/// `AuthenticatorProvider`
+///
+/// Plug in a custom implementation to fetch or refresh a token on demand
+/// instead of signing in once with a fixed credential struct like
+/// [`Root`](crate::param::Root). [`Pool`](crate::Pool)'s reconnect path signs
+/// every connection it opens back in, with
+/// [`initial_response`](Self::initial_response) until a token has been issued
+/// and [`evaluate_challenge`](Self::evaluate_challenge) after that, so a session backed by a refreshing token
+/// survives a dropped connection without the caller manually calling
+/// `signin` again.
+pub trait Authenticator: Debug + Send + Sync {
+	/// Returns the credentials sent the first time a connection signs in
+	fn initial_response(&self) -> Pin<Box<dyn Future<Output = Result<Jwt>> + Send + Sync + '_>>;
+
+	/// Returns the credentials to re-authenticate with after a reconnect
+	///
+	/// `previous` is the token the connection was last authenticated with,
+	/// which a token-refreshing implementation can use to decide whether it
+	/// is still valid or needs to be renewed before being sent again.
+	fn evaluate_challenge(
+		&self,
+		previous: &Jwt,
+	) -> Pin<Box<dyn Future<Output = Result<Jwt>> + Send + Sync + '_>>;
+}
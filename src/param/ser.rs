@@ -0,0 +1,435 @@
+use crate::ErrorKind;
+use crate::Result;
+use rust_decimal::Decimal;
+use serde::ser;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use surrealdb::sql::Array;
+use surrealdb::sql::Number;
+use surrealdb::sql::Object;
+use surrealdb::sql::Strand;
+use surrealdb::sql::Value;
+
+/// The private field name serde_json's `arbitrary_precision` feature uses to
+/// smuggle a full-precision numeral through as a lone string field on a
+/// struct, instead of an ordinary JSON number
+///
+/// `rust_decimal`'s `serde-arbitrary-precision` feature serializes a
+/// [`Decimal`] the same way, via `serialize_struct(DECIMAL_TOKEN, 1)` with a
+/// single `DECIMAL_TOKEN` field holding its string form -- unlike `Datetime`,
+/// `Duration`, and `Thing`, whose `Serialize` impls we can catch with a
+/// `serialize_newtype_struct` hook, `Decimal` doesn't go through one at all
+/// by default (it calls `serialize_str` directly, indistinguishable from any
+/// other string), so this struct shape is the only hook actually available
+/// without that feature flag a plain string can't be told apart from one.
+const DECIMAL_TOKEN: &str = "$serde_json::private::Number";
+
+/// Serializes `value` directly into a [`Value`], bypassing the JSON round-trip
+/// that [`from_json`](super::from_json) needs.
+///
+/// Newtype wrappers named `Datetime`, `Duration`, and `Thing` are recognized
+/// and turned into their native [`Value`] variant (via their `FromStr` impl)
+/// instead of being flattened into a string, so binding a record id or a
+/// datetime keeps its type when it reaches the server. A [`Decimal`] (built
+/// with its `serde-arbitrary-precision` feature) is recognized the same way,
+/// via the differently-shaped hook described on [`DECIMAL_TOKEN`], so it
+/// keeps its exact precision as a native [`Value::Number`] instead of being
+/// rounded through an `f64` or flattened into a string.
+pub fn to_value<T>(value: T) -> Result<Value>
+where
+	T: Serialize,
+{
+	value.serialize(Serializer)
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	type SerializeSeq = SerializeVec;
+	type SerializeTuple = SerializeVec;
+	type SerializeTupleStruct = SerializeVec;
+	type SerializeTupleVariant = SerializeTupleVariant;
+	type SerializeMap = SerializeMap;
+	type SerializeStruct = SerializeStructField;
+	type SerializeStructVariant = SerializeStructVariant;
+
+	fn serialize_bool(self, v: bool) -> Result<Value> {
+		Ok(v.into())
+	}
+
+	fn serialize_i8(self, v: i8) -> Result<Value> {
+		self.serialize_i64(v.into())
+	}
+
+	fn serialize_i16(self, v: i16) -> Result<Value> {
+		self.serialize_i64(v.into())
+	}
+
+	fn serialize_i32(self, v: i32) -> Result<Value> {
+		self.serialize_i64(v.into())
+	}
+
+	fn serialize_i64(self, v: i64) -> Result<Value> {
+		Ok(v.into())
+	}
+
+	fn serialize_u8(self, v: u8) -> Result<Value> {
+		self.serialize_u64(v.into())
+	}
+
+	fn serialize_u16(self, v: u16) -> Result<Value> {
+		self.serialize_u64(v.into())
+	}
+
+	fn serialize_u32(self, v: u32) -> Result<Value> {
+		self.serialize_u64(v.into())
+	}
+
+	fn serialize_u64(self, v: u64) -> Result<Value> {
+		Ok(v.into())
+	}
+
+	fn serialize_f32(self, v: f32) -> Result<Value> {
+		self.serialize_f64(v.into())
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<Value> {
+		Ok(v.into())
+	}
+
+	fn serialize_char(self, v: char) -> Result<Value> {
+		self.serialize_str(&v.to_string())
+	}
+
+	fn serialize_str(self, v: &str) -> Result<Value> {
+		Ok(Value::Strand(Strand(v.to_owned())))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+		Ok(Value::Bytes(v.to_vec().into()))
+	}
+
+	fn serialize_none(self) -> Result<Value> {
+		Ok(Value::None)
+	}
+
+	fn serialize_some<T>(self, value: &T) -> Result<Value>
+	where
+		T: ?Sized + Serialize,
+	{
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Value> {
+		Ok(Value::None)
+	}
+
+	fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+		Ok(Value::None)
+	}
+
+	fn serialize_unit_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		variant: &'static str,
+	) -> Result<Value> {
+		self.serialize_str(variant)
+	}
+
+	fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Value>
+	where
+		T: ?Sized + Serialize,
+	{
+		match name {
+			"Datetime" | "Duration" | "Thing" => {
+				let inner = value.serialize(Serializer)?;
+				let Value::Strand(Strand(raw)) = inner else {
+					return Err(ErrorKind::InvalidBindings.with_context(name));
+				};
+				raw.parse().map_err(|_| ErrorKind::InvalidBindings.with_context(name))
+			}
+			_ => value.serialize(self),
+		}
+	}
+
+	fn serialize_newtype_variant<T>(
+		self,
+		_name: &'static str,
+		_index: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<Value>
+	where
+		T: ?Sized + Serialize,
+	{
+		let mut object = BTreeMap::new();
+		object.insert(variant.to_owned(), value.serialize(Serializer)?);
+		Ok(Value::Object(Object(object)))
+	}
+
+	fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+		Ok(SerializeVec {
+			vec: Vec::with_capacity(len.unwrap_or_default()),
+		})
+	}
+
+	fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		_name: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleStruct> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		variant: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleVariant> {
+		Ok(SerializeTupleVariant {
+			variant,
+			vec: Vec::with_capacity(len),
+		})
+	}
+
+	fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+		Ok(SerializeMap {
+			object: BTreeMap::new(),
+			next_key: None,
+		})
+	}
+
+	fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+		if name == DECIMAL_TOKEN {
+			return Ok(SerializeStructField::Decimal(None));
+		}
+		Ok(SerializeStructField::Object(SerializeMap {
+			object: BTreeMap::new(),
+			next_key: None,
+		}))
+	}
+
+	fn serialize_struct_variant(
+		self,
+		_name: &'static str,
+		_index: u32,
+		variant: &'static str,
+		_len: usize,
+	) -> Result<Self::SerializeStructVariant> {
+		Ok(SerializeStructVariant {
+			variant,
+			object: BTreeMap::new(),
+		})
+	}
+}
+
+struct SerializeVec {
+	vec: Vec<Value>,
+}
+
+impl ser::SerializeSeq for SerializeVec {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		self.vec.push(value.serialize(Serializer)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value> {
+		Ok(Value::Array(Array(self.vec)))
+	}
+}
+
+impl ser::SerializeTuple for SerializeVec {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		ser::SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Value> {
+		ser::SerializeSeq::end(self)
+	}
+}
+
+impl ser::SerializeTupleStruct for SerializeVec {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		ser::SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Value> {
+		ser::SerializeSeq::end(self)
+	}
+}
+
+struct SerializeTupleVariant {
+	variant: &'static str,
+	vec: Vec<Value>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		self.vec.push(value.serialize(Serializer)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value> {
+		let mut object = BTreeMap::new();
+		object.insert(self.variant.to_owned(), Value::Array(Array(self.vec)));
+		Ok(Value::Object(Object(object)))
+	}
+}
+
+struct SerializeMap {
+	object: BTreeMap<String, Value>,
+	next_key: Option<String>,
+}
+
+impl ser::SerializeMap for SerializeMap {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		let key = key.serialize(Serializer)?;
+		self.next_key = Some(match key {
+			Value::Strand(Strand(key)) => key,
+			key => key.to_string(),
+		});
+		Ok(())
+	}
+
+	fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		let key = self.next_key.take().ok_or_else(|| {
+			ErrorKind::InvalidBindings.with_context("serialize_value called before serialize_key")
+		})?;
+		self.object.insert(key, value.serialize(Serializer)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value> {
+		Ok(Value::Object(Object(self.object)))
+	}
+}
+
+impl ser::SerializeStruct for SerializeMap {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		self.object.insert(key.to_owned(), value.serialize(Serializer)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value> {
+		Ok(Value::Object(Object(self.object)))
+	}
+}
+
+/// Either an ordinary struct being built into a [`Value::Object`], or the
+/// single [`DECIMAL_TOKEN`] field of a [`Decimal`] being built into a native
+/// [`Value::Number`] instead
+enum SerializeStructField {
+	Object(SerializeMap),
+	Decimal(Option<Decimal>),
+}
+
+impl ser::SerializeStruct for SerializeStructField {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		match self {
+			Self::Object(map) => ser::SerializeStruct::serialize_field(map, key, value),
+			Self::Decimal(decimal) => {
+				if key != DECIMAL_TOKEN {
+					return Ok(());
+				}
+				let inner = value.serialize(Serializer)?;
+				let Value::Strand(Strand(digits)) = inner else {
+					return Err(ErrorKind::InvalidBindings.with_context("Decimal"));
+				};
+				*decimal = Some(
+					digits.parse().map_err(|_| ErrorKind::InvalidBindings.with_context("Decimal"))?,
+				);
+				Ok(())
+			}
+		}
+	}
+
+	fn end(self) -> Result<Value> {
+		match self {
+			Self::Object(map) => ser::SerializeStruct::end(map),
+			Self::Decimal(decimal) => {
+				let decimal = decimal
+					.ok_or_else(|| ErrorKind::InvalidBindings.with_context("Decimal"))?;
+				Ok(Value::Number(Number::Decimal(decimal)))
+			}
+		}
+	}
+}
+
+struct SerializeStructVariant {
+	variant: &'static str,
+	object: BTreeMap<String, Value>,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant {
+	type Ok = Value;
+	type Error = crate::Error;
+
+	fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+	where
+		T: ?Sized + Serialize,
+	{
+		self.object.insert(key.to_owned(), value.serialize(Serializer)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Value> {
+		let mut object = BTreeMap::new();
+		object.insert(self.variant.to_owned(), Value::Object(Object(self.object)));
+		Ok(Value::Object(Object(object)))
+	}
+}
@@ -0,0 +1,61 @@
+use crate::embedded::Db;
+use crate::param::ServerAddrs;
+use crate::param::Strict;
+use crate::param::ToServerAddrs;
+use crate::storage::Any;
+use crate::ErrorKind;
+use crate::Result;
+use url::Url;
+
+/// Parses `address` as one of the embedded storage engine schemes
+///
+/// `Any` only dispatches to an embedded [`Datastore`](surrealdb::kvs::Datastore)
+/// variant, not a remote protocol -- every [`ToServerAddrs<Any>`] impl fixes
+/// `Client = Db`, so there's nowhere for a `ws://`/`http://` endpoint to go.
+/// Connect to a remote `surreal` server with [`Ws`](crate::protocol::Ws) or
+/// [`Http`](crate::protocol::Http) directly instead.
+fn parse(address: &str) -> Result<Url> {
+	let url = Url::parse(address)
+		.map_err(|error| ErrorKind::InvalidUrl.with_message(error.to_string()))?;
+	match url.scheme() {
+		"mem" | "file" | "rocksdb" | "indxdb" | "tikv" | "fdb" => Ok(url),
+		scheme => Err(ErrorKind::InvalidUrl.with_message(format!(
+			"`{scheme}` is not an embedded storage engine understood by `Any` -- \
+			connect to a remote server with `Ws` or `Http` instead"
+		))),
+	}
+}
+
+impl ToServerAddrs<Any> for &str {
+	type Client = Db;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		Ok(ServerAddrs {
+			endpoint: parse(self)?,
+			strict: false,
+			#[cfg(any(feature = "native-tls", feature = "rustls"))]
+			tls_config: None,
+		})
+	}
+}
+
+impl ToServerAddrs<Any> for String {
+	type Client = Db;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		ToServerAddrs::<Any>::to_server_addrs(self.as_str())
+	}
+}
+
+impl<T> ToServerAddrs<Any> for (T, Strict)
+where
+	T: ToServerAddrs<Any>,
+{
+	type Client = Db;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		let mut address = self.0.to_server_addrs()?;
+		address.strict = true;
+		Ok(address)
+	}
+}
@@ -0,0 +1,56 @@
+use crate::net::UdsClient;
+use crate::param::ServerAddrs;
+use crate::param::Strict;
+use crate::param::ToServerAddrs;
+use crate::protocol::Uds;
+use crate::Result;
+use std::path::Path;
+use url::Url;
+
+impl ToServerAddrs<Uds> for &str {
+	type Client = UdsClient;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		let path = self.strip_prefix("unix:").unwrap_or(self);
+		Ok(ServerAddrs {
+			endpoint: Url::parse(&format!("unix://{path}"))?,
+			strict: false,
+			#[cfg(any(feature = "native-tls", feature = "rustls"))]
+			tls_config: None,
+		})
+	}
+}
+
+impl ToServerAddrs<Uds> for String {
+	type Client = UdsClient;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		ToServerAddrs::<Uds>::to_server_addrs(self.as_str())
+	}
+}
+
+impl ToServerAddrs<Uds> for &Path {
+	type Client = UdsClient;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		Ok(ServerAddrs {
+			endpoint: Url::parse(&format!("unix://{}", self.display()))?,
+			strict: false,
+			#[cfg(any(feature = "native-tls", feature = "rustls"))]
+			tls_config: None,
+		})
+	}
+}
+
+impl<T> ToServerAddrs<Uds> for (T, Strict)
+where
+	T: ToServerAddrs<Uds>,
+{
+	type Client = UdsClient;
+
+	fn to_server_addrs(self) -> Result<ServerAddrs> {
+		let mut address = self.0.to_server_addrs()?;
+		address.strict = true;
+		Ok(address)
+	}
+}
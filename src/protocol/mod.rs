@@ -0,0 +1,51 @@
+//! Protocols for connecting to a remote `surreal` server
+
+#[cfg(feature = "uds")]
+mod uds;
+
+#[cfg(feature = "uds")]
+pub use uds::Uds;
+
+/// The WebSocket protocol
+///
+/// Connects over a persistent WebSocket, which also carries live query
+/// notifications and is the default protocol recommended for most uses.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> surrealdb_rs::Result<()> {
+/// use surrealdb_rs::protocol::Ws;
+/// use surrealdb_rs::Surreal;
+///
+/// let db = Surreal::connect::<Ws>("localhost:8000").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "ws")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ws")))]
+#[derive(Debug)]
+pub struct Ws;
+
+/// The HTTP protocol
+///
+/// Connects over plain HTTP request/response instead of a persistent
+/// WebSocket; live queries aren't available over this protocol.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> surrealdb_rs::Result<()> {
+/// use surrealdb_rs::protocol::Http;
+/// use surrealdb_rs::Surreal;
+///
+/// let db = Surreal::connect::<Http>("localhost:8000").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+#[derive(Debug)]
+pub struct Http;
@@ -0,0 +1,22 @@
+/// Unix domain socket protocol
+///
+/// Connects to a local `surreal` instance over a Unix domain socket instead
+/// of TCP, for sidecar/colocated deployments that want to skip the network
+/// stack entirely.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> surrealdb_rs::Result<()> {
+/// use surrealdb_rs::protocol::Uds;
+/// use surrealdb_rs::Surreal;
+///
+/// let db = Surreal::connect::<Uds>("unix:/run/surreal.sock").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "uds")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uds")))]
+#[derive(Debug)]
+pub struct Uds;
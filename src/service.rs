@@ -0,0 +1,128 @@
+//! `tower::Service` support for [`Surreal`]
+
+use crate::param::Param;
+use crate::Connection;
+use crate::Error;
+use crate::ErrorKind;
+use crate::ExtractRouter;
+use crate::Method;
+use crate::Result;
+use crate::Surreal;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use surrealdb::sql::Value;
+use tower::Service;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Wraps this client in a [`tower::Service`]
+	///
+	/// The returned [`SurrealService`] accepts a `(`[`Method`]`, `[`Param`]`)`
+	/// request and resolves to the raw [`Value`] the router returned for it,
+	/// so it can be layered with standard Tower middleware -- retries,
+	/// timeouts, concurrency limits, rate limiting -- without this crate
+	/// having to reimplement any of it. `poll_ready` reflects whether the
+	/// underlying `flume` channel has room, per the capacity configured via
+	/// [`Connect::with_capacity`](crate::Connect::with_capacity).
+	///
+	/// [`Method::Query`] isn't accepted: it resolves to a `QueryResponse`, not
+	/// a [`Value`], which doesn't fit `SurrealService`'s single fixed
+	/// `Response` type. Issue queries through [`Surreal::query`] directly
+	/// instead of through this service.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// use std::time::Duration;
+	/// use tower::ServiceBuilder;
+	/// use tower::ServiceExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let mut service = ServiceBuilder::new()
+	///     .timeout(Duration::from_secs(5))
+	///     .service(db.tower());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn tower(&self) -> SurrealService<C> {
+		SurrealService {
+			client: self.clone(),
+		}
+	}
+}
+
+/// A [`tower::Service`] wrapping a [`Surreal`] client
+///
+/// Constructed via [`Surreal::tower`].
+#[derive(Debug, Clone)]
+pub struct SurrealService<C: Connection> {
+	client: Surreal<C>,
+}
+
+impl<C> Service<(Method, Param)> for SurrealService<C>
+where
+	C: Connection,
+{
+	type Response = Value;
+	type Error = Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Value>> + Send + Sync>>;
+
+	fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+		match self.client.router.extract() {
+			Ok(router) if router.is_ready() => Poll::Ready(Ok(())),
+			Ok(_) => {
+				// the bounded channel is full. flume's `Sender` has no
+				// waker-based "room available" signal to register interest
+				// with directly, so watch it on a backoff in the background
+				// and only wake the caller once there's actually room (or
+				// the router disconnects), instead of re-waking ourselves
+				// unconditionally and spinning as fast as we're re-polled.
+				let waker = cx.waker().clone();
+				let client = self.client.clone();
+				tokio::spawn(async move {
+					let mut backoff = Duration::from_millis(1);
+					loop {
+						match client.router.extract() {
+							Ok(router) if router.is_ready() => break,
+							Ok(router) if router.is_disconnected() => break,
+							Ok(_) => {}
+							Err(_) => break,
+						}
+						tokio::time::sleep(backoff).await;
+						backoff = (backoff * 2).min(Duration::from_millis(50));
+					}
+					waker.wake();
+				});
+				Poll::Pending
+			}
+			Err(error) => Poll::Ready(Err(error)),
+		}
+	}
+
+	fn call(&mut self, (method, param): (Method, Param)) -> Self::Future {
+		let client = self.client.clone();
+		Box::pin(async move {
+			// `execute`/`recv` assume a single `Value` response; `Method::Query`
+			// actually resolves to a `QueryResponse` and would panic inside
+			// `recv` instead, so it's rejected here rather than dispatched
+			if matches!(method, Method::Query) {
+				return Err(ErrorKind::Query.with_context(
+					"Method::Query is not supported through SurrealService: it resolves to a \
+					 QueryResponse, not a Value; call `Surreal::query` directly instead",
+				));
+			}
+			let router = client.router.extract()?;
+			let mut conn = C::new(method);
+			conn.execute(router, param).await
+		})
+	}
+}
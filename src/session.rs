@@ -0,0 +1,146 @@
+//! `async_session::SessionStore` support for [`Surreal`]
+
+use crate::Connection;
+use crate::Result;
+use crate::Surreal;
+use async_session::async_trait;
+use async_session::Session;
+use async_session::SessionStore as AsyncSessionStore;
+use serde::Deserialize;
+use serde::Serialize;
+
+impl<C> Surreal<C>
+where
+	C: Connection,
+{
+	/// Wraps this client in an `async_session::SessionStore` backed by `table`
+	///
+	/// Call [`SessionStore::initialize`] once (e.g. alongside [`migrate`](Self::migrate))
+	/// before handing the store to a session middleware, and schedule
+	/// [`SessionStore::sweep`] periodically to clear expired rows; neither
+	/// runs implicitly.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # use surrealdb_rs::{Result, Surreal};
+	/// # use surrealdb_rs::net::WsClient;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<()> {
+	/// # let db = Surreal::<WsClient>::new();
+	/// let store = db.sessions("sessions");
+	/// store.initialize().await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn sessions(&self, table: impl Into<String>) -> SessionStore<C> {
+		SessionStore {
+			client: self.clone(),
+			table: table.into(),
+		}
+	}
+}
+
+/// An `async_session::SessionStore` backed by a [`Surreal`] client
+///
+/// Constructed via [`Surreal::sessions`].
+#[derive(Debug, Clone)]
+pub struct SessionStore<C: Connection> {
+	client: Surreal<C>,
+	table: String,
+}
+
+/// The row a [`Session`] is persisted as, keyed by `id` in `self.table`
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+	session: String,
+	expires: Option<i64>,
+}
+
+impl<C> SessionStore<C>
+where
+	C: Connection,
+{
+	/// Defines `self.table` and an index on its `expires` column
+	///
+	/// Idempotent, like `DEFINE TABLE`/`DEFINE INDEX` themselves; safe to call
+	/// on every startup rather than only once.
+	pub async fn initialize(&self) -> Result<()> {
+		self.client
+			.query(format!("DEFINE TABLE {table}", table = self.table))
+			.query(format!(
+				"DEFINE INDEX {table}_expires ON TABLE {table} COLUMNS expires",
+				table = self.table
+			))
+			.await?;
+		Ok(())
+	}
+
+	/// Deletes every row in `self.table` whose `expires` has passed
+	///
+	/// Not run automatically; call this periodically, e.g. from a
+	/// `tokio::time::interval` loop, to reclaim expired sessions the browser
+	/// never came back to destroy.
+	pub async fn sweep(&self) -> Result<()> {
+		self.client
+			.query(format!(
+				"DELETE {table} WHERE expires != NULL AND expires < time::unix(time::now())",
+				table = self.table
+			))
+			.await?;
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl<C> AsyncSessionStore for SessionStore<C>
+where
+	C: Connection,
+{
+	async fn load_session(&self, cookie_value: String) -> async_session::Result<Option<Session>> {
+		let id = Session::id_from_cookie_value(&cookie_value)?;
+		let record: Option<Record> = self
+			.client
+			.select((self.table.as_str(), id.as_str()))
+			.await
+			.map_err(|error| async_session::Error::msg(error.to_string()))?;
+		let session = match record {
+			Some(record) => serde_json::from_str(&record.session)?,
+			None => return Ok(None),
+		};
+		Ok(session.validate())
+	}
+
+	async fn store_session(&self, session: Session) -> async_session::Result<Option<String>> {
+		let record = Record {
+			session: serde_json::to_string(&session)?,
+			expires: session.expiry().map(|expires| expires.unix_timestamp()),
+		};
+		let _: Option<Record> = self
+			.client
+			.upsert((self.table.as_str(), session.id()))
+			.content(record)
+			.await
+			.map_err(|error| async_session::Error::msg(error.to_string()))?;
+		session.reset_data_changed();
+		Ok(session.into_cookie_value())
+	}
+
+	async fn destroy_session(&self, session: Session) -> async_session::Result<()> {
+		let _: Option<Record> = self
+			.client
+			.delete((self.table.as_str(), session.id()))
+			.await
+			.map_err(|error| async_session::Error::msg(error.to_string()))?;
+		Ok(())
+	}
+
+	async fn clear_store(&self) -> async_session::Result<()> {
+		let _: Vec<Record> = self
+			.client
+			.delete(self.table.as_str())
+			.await
+			.map_err(|error| async_session::Error::msg(error.to_string()))?;
+		Ok(())
+	}
+}
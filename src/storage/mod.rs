@@ -266,6 +266,36 @@ pub struct TiKv;
 #[derive(Debug)]
 pub struct FDb;
 
+/// Any embedded database engine, selected at runtime from the scheme of the
+/// address passed to [`connect`](crate::Surreal::connect)
+///
+/// Use this when the storage engine isn't known until runtime, for example
+/// when it comes from a configuration file or an environment variable. This
+/// only covers the embedded engines (`mem://`, `file://`, `rocksdb://`,
+/// `indxdb://`, `tikv://`, `fdb://`) -- it doesn't dispatch to a remote
+/// `surreal` server, since those connect through a different client type
+/// ([`Ws`](crate::protocol::Ws)/[`Http`](crate::protocol::Http)) that can't
+/// be selected at runtime behind the same `Client` associated type. Connect
+/// to a remote server directly with whichever of those you need instead.
+///
+/// # Examples
+///
+/// Instantiating a runtime-selected instance
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> surrealdb_rs::Result<()> {
+/// use surrealdb_rs::Surreal;
+/// use surrealdb_rs::storage::Any;
+///
+/// let address = std::env::var("DATABASE_URL").unwrap_or_else(|_| "mem://".to_owned());
+/// let db = Surreal::connect::<Any>(address).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Any;
+
 fn process(responses: Vec<Response>) -> Result<QueryResponse> {
 	let mut vec = Vec::with_capacity(responses.len());
 	for response in responses {
@@ -307,6 +337,60 @@ async fn take(one: bool, responses: Vec<Response>) -> Result<Value> {
 	}
 }
 
+/// Streams a full database export into `writer`
+///
+/// Used by [`Method::Export`] once it has opened the destination file; kept
+/// generic so the same pumping logic can be reused by a caller that wants to
+/// export straight into something other than a file, such as an upload or a
+/// compression encoder.
+#[cfg(not(target_arch = "wasm32"))]
+async fn export_into(
+	kvs: &'static Datastore,
+	session: &mut Session,
+	mut writer: impl io::AsyncWrite + Send + Unpin + 'static,
+) -> Result<()> {
+	let (tx, rx) = channel::new(1);
+	let ns = session.ns.clone().unwrap_or_default();
+	let db = session.db.clone().unwrap_or_default();
+	tokio::spawn(async move {
+		if let Err(error) = kvs.export(ns, db, tx).await {
+			tracing::error!("{error}");
+		}
+	});
+	let (mut duplex_writer, mut duplex_reader) = io::duplex(10_240);
+	tokio::spawn(async move {
+		while let Ok(value) = rx.recv().await {
+			if let Err(error) = duplex_writer.write_all(&value).await {
+				tracing::error!("{error}");
+			}
+		}
+	});
+	io::copy(&mut duplex_reader, &mut writer).await?;
+	Ok(())
+}
+
+/// Applies every statement read from `reader` to the database
+///
+/// Used by [`Method::Import`] once it has opened the source file; kept
+/// generic so the same logic can be reused by a caller that wants to import
+/// from something other than a file, such as a network stream.
+#[cfg(not(target_arch = "wasm32"))]
+async fn import_from(
+	kvs: &'static Datastore,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	strict: bool,
+	mut reader: impl io::AsyncRead + Unpin,
+) -> Result<()> {
+	let mut statements = String::new();
+	reader.read_to_string(&mut statements).await?;
+	let responses = kvs.execute(&statements, session, Some(vars.clone()), strict).await?;
+	for response in responses {
+		response.result?;
+	}
+	Ok(())
+}
+
 async fn router(
 	(method, param): (Method, Param),
 	#[cfg(target_arch = "wasm32")] kvs: &Datastore,
@@ -360,6 +444,20 @@ async fn router(
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
+		Method::Insert => {
+			let (one, statement) = crate::insert_statement(&mut params);
+			let query = Query(Statements(vec![Statement::Insert(statement)]));
+			let response = kvs.process(query, &*session, Some(vars.clone()), strict).await?;
+			let value = take(one, response).await?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::Upsert => {
+			let (one, statement) = crate::upsert_statement(&mut params);
+			let query = Query(Statements(vec![Statement::Insert(statement)]));
+			let response = kvs.process(query, &*session, Some(vars.clone()), strict).await?;
+			let value = take(one, response).await?;
+			Ok(DbResponse::Other(value))
+		}
 		Method::Select => {
 			let (one, statement) = crate::select_statement(&mut params);
 			let query = Query(Statements(vec![Statement::Select(statement)]));
@@ -391,37 +489,16 @@ async fn router(
 		#[cfg(not(target_arch = "wasm32"))]
 		Method::Export => {
 			let file = param.file.expect("file to export into");
-			let (tx, rx) = channel::new(1);
-			let ns = session.ns.clone().unwrap_or_default();
-			let db = session.db.clone().unwrap_or_default();
-			tokio::spawn(async move {
-				if let Err(error) = kvs.export(ns, db, tx).await {
-					tracing::error!("{error}");
-				}
-			});
-			let (mut writer, mut reader) = io::duplex(10_240);
-			tokio::spawn(async move {
-				while let Ok(value) = rx.recv().await {
-					if let Err(error) = writer.write_all(&value).await {
-						tracing::error!("{error}");
-					}
-				}
-			});
-			let mut file =
+			let file =
 				OpenOptions::new().write(true).create(true).truncate(true).open(file).await?;
-			io::copy(&mut reader, &mut file).await?;
+			export_into(kvs, session, file).await?;
 			Ok(DbResponse::Other(Value::None))
 		}
 		#[cfg(not(target_arch = "wasm32"))]
 		Method::Import => {
 			let file = param.file.expect("file to import from");
-			let mut file = OpenOptions::new().read(true).open(file).await?;
-			let mut statements = String::new();
-			file.read_to_string(&mut statements).await?;
-			let responses = kvs.execute(&statements, &*session, Some(vars.clone()), strict).await?;
-			for response in responses {
-				response.result?;
-			}
+			let file = OpenOptions::new().read(true).open(file).await?;
+			import_from(kvs, &*session, vars, strict, file).await?;
 			Ok(DbResponse::Other(Value::None))
 		}
 		Method::Health => Ok(DbResponse::Other(Value::None)),
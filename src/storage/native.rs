@@ -1,5 +1,7 @@
 use super::DbRoute;
 use crate::embedded::Db;
+use crate::method::live::Action;
+use crate::method::live::Notification;
 use crate::param::from_value;
 use crate::param::DbResponse;
 use crate::param::Param;
@@ -13,18 +15,30 @@ use crate::Router;
 use crate::Surreal;
 use flume::Receiver;
 use flume::Sender;
+use futures::stream::Stream;
 use futures::StreamExt;
 use once_cell::sync::OnceCell;
 use serde::de::DeserializeOwned;
 use std::collections::BTreeMap;
+#[cfg(feature = "ws")]
+use std::collections::HashMap;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
+use std::sync::Mutex;
 #[cfg(feature = "ws")]
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
+use surrealdb::sql::Uuid as SqlUuid;
+use surrealdb::sql::Value;
 use surrealdb::Datastore;
 use surrealdb::Session;
+use uuid::Uuid;
+
+/// Maps a live query id to the sender half of the route it was opened on, so
+/// that notifications pushed by the `Datastore` can be forwarded to the
+/// stream the caller is polling.
+type LiveQueries = Arc<Mutex<BTreeMap<Uuid, Sender<Result<DbResponse>>>>>;
 
 static DB: OnceCell<Datastore> = OnceCell::new();
 
@@ -62,7 +76,12 @@ impl Connection for Db {
 					sender: route_tx,
 					#[cfg(feature = "ws")]
 					last_id: AtomicI64::new(0),
+					#[cfg(feature = "ws")]
+					live_senders: Mutex::new(HashMap::new()),
+					capabilities: OnceCell::new(),
+					transaction_lock: tokio::sync::Mutex::new(()),
 				})),
+				authenticator: OnceCell::new(),
 			})
 		})
 	}
@@ -95,7 +114,7 @@ impl Connection for Db {
 			tracing::trace!("Response {response:?}");
 			match response? {
 				DbResponse::Other(value) => from_value(&value),
-				DbResponse::Query(..) => unreachable!(),
+				DbResponse::Query(..) | DbResponse::Notification(..) => unreachable!(),
 			}
 		})
 	}
@@ -109,10 +128,26 @@ impl Connection for Db {
 			tracing::trace!("Response {response:?}");
 			match response? {
 				DbResponse::Query(results) => Ok(results),
-				DbResponse::Other(..) => unreachable!(),
+				DbResponse::Other(..) | DbResponse::Notification(..) => unreachable!(),
 			}
 		})
 	}
+
+	fn recv_notifications<R>(
+		&mut self,
+		rx: Receiver<Self::Response>,
+	) -> Pin<Box<dyn Stream<Item = Result<Notification<R>>> + Send + Sync>>
+	where
+		R: DeserializeOwned + Send + Sync + 'static,
+	{
+		Box::pin(rx.into_stream().map(|response| match response? {
+			DbResponse::Notification(notification) => Ok(Notification {
+				action: notification.action,
+				data: from_value(&notification.data)?,
+			}),
+			DbResponse::Other(..) | DbResponse::Query(..) => unreachable!(),
+		}))
+	}
 }
 
 fn router(address: ServerAddrs, conn_tx: Sender<Result<()>>, route_rx: Receiver<Option<DbRoute>>) {
@@ -140,8 +175,25 @@ fn router(address: ServerAddrs, conn_tx: Sender<Result<()>>, route_rx: Receiver<
 		let mut session = Session::for_kv();
 		let mut vars = BTreeMap::new();
 		let mut stream = route_rx.into_stream();
+		let live_queries: LiveQueries = Arc::new(Mutex::new(BTreeMap::new()));
+
+		tokio::spawn(forward_notifications(kvs, live_queries.clone()));
 
 		while let Some(Some(route)) = stream.next().await {
+			// A request to stream an already-registered live query just
+			// hands its response channel to the forwarding task and is kept
+			// open rather than answered once, like every other method.
+			if let (Method::Live, [Value::Uuid(SqlUuid(id))]) =
+				(route.request.0, &route.request.1.other[..])
+			{
+				live_queries.lock().unwrap().insert(*id, route.response);
+				continue;
+			}
+			if let (Method::Kill, [Value::Uuid(SqlUuid(id))]) =
+				(route.request.0, &route.request.1.other[..])
+			{
+				live_queries.lock().unwrap().remove(id);
+			}
 			match super::router(route.request, kvs, &mut session, &mut vars, address.strict).await {
 				Ok(value) => {
 					let _ = route.response.into_send_async(Ok(value)).await;
@@ -153,3 +205,24 @@ fn router(address: ServerAddrs, conn_tx: Sender<Result<()>>, route_rx: Receiver<
 		}
 	});
 }
+
+/// Forwards every notification the embedded `Datastore` produces to the
+/// channel registered for its live query id, if any is still subscribed.
+async fn forward_notifications(kvs: &'static Datastore, live_queries: LiveQueries) {
+	let mut notifications = kvs.notifications();
+	while let Some(notification) = notifications.next().await {
+		let sender = live_queries.lock().unwrap().get(&notification.id).cloned();
+		if let Some(sender) = sender {
+			let action = match notification.action {
+				surrealdb::sql::Action::Create => Action::Create,
+				surrealdb::sql::Action::Update => Action::Update,
+				surrealdb::sql::Action::Delete => Action::Delete,
+			};
+			let response = DbResponse::Notification(crate::param::Notification {
+				action,
+				data: notification.result,
+			});
+			let _ = sender.send_async(Ok(response)).await;
+		}
+	}
+}